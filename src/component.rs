@@ -1,13 +1,41 @@
-//! Provides the [`SyncSsr`] and [`SyncSsrSignal`] components.
-use leptos::{children::Children, component, view, IntoView};
+//! Provides the [`SyncSsr`] and [`SyncSsrSignal`] components, along with
+//! the [`WaitReady`] and [`WaitCoReady`] components that wait on what the
+//! former provide, and the [`DeclareWriters`] component for routes.
+//!
+//! Both `SyncSsr` and `SyncSsrSignal` are thin wrappers around a
+//! [`SyncScope`], which may also be rendered through the [`SyncSsrScope`]
+//! component to provide the primitives of both at once, or be
+//! [entered](SyncScope::enter) directly for view code that is built
+//! without any of these components.
+use std::{future::Future, sync::Arc, time::Duration};
+
+use futures::{future::BoxFuture, FutureExt};
+
+use leptos::{
+    children::{Children, ChildrenFn, ViewFnOnce},
+    component,
+    oco::Oco,
+    prelude::Suspend,
+    suspense::Suspense,
+    view, IntoView,
+};
 // See: https://github.com/leptos-rs/leptos/pull/4071
 #[allow(unused_imports)]
 use leptos::prelude::IntoMaybeErased;
 
+use crate::{observer::SyncObserver, CoReady, Ready, ReadyHandle};
+
+mod scope;
+
+pub use scope::{
+    ScopeCompletion, ScopeSetup, SyncScope, SyncScopeHandle, SyncSsrScope, SyncSsrScopeProps,
+};
+
 #[cfg(feature = "ssr")]
 mod ssr {
-    pub use crate::ready::{CoReadyCoordinator, Ready};
-    pub use leptos::context::Provider;
+    pub use crate::{ready::CoReadyCoordinator, ReadyFuture};
+    pub use futures::channel::oneshot;
+    pub use leptos::{context::use_context, reactive::computed::ScopedFuture};
 }
 
 #[cfg(feature = "ssr")]
@@ -87,36 +115,127 @@ use ssr::*;
 /// may be set by other components enclosed inside the `<Routes>` so
 /// that SSR be done in the expected order to ensure proper hydration by
 /// the client.
+///
+/// An optional [`SyncObserver`] may be provided, which will be used in
+/// place of the one that may be provided as a context.  The optional
+/// name is reported to the observer along with the events for the
+/// `Ready` provided by this component, and it also allows the `Ready`
+/// be found by [`Ready::handle_named`] from within nested `SyncSsr`.
+///
+/// The `Ready` may be held open past the rendering of this component
+/// through [`Ready::hold`], and the optional `stall_deadline` is how long
+/// such a guard may be held before it is reported as stalled.
 #[component]
-pub fn SyncSsr(children: Children) -> impl IntoView {
-    // leptos::logging::log!("entering SyncSsr");
-    #[cfg(feature = "ssr")]
-    let ready = Ready::new();
+pub fn SyncSsr(
+    children: Children,
+    #[prop(optional, into)] name: Option<Oco<'static, str>>,
+    #[prop(optional, into)] observer: Option<Arc<dyn SyncObserver>>,
+    #[prop(optional, into)] stall_deadline: Option<Duration>,
+) -> impl IntoView {
+    SyncScope {
+        name,
+        observer,
+        stall_deadline,
+        ..SyncScope::new().coordinator(false)
+    }
+    .render(children)
+}
 
-    #[cfg(feature = "ssr")]
-    let exit = {
-        let ready = ready.clone();
-        move || {
-            ready.complete();
-            // leptos::logging::log!("exiting SyncSsr");
-        }
+/// This component waits for the [`Ready`] provided by the nearest
+/// [`SyncSsr`] before rendering its children.
+///
+/// This allows any components be made into readers that will see the
+/// values set by the components later in the view tree under SSR,
+/// without having to write a custom resource that would wait on the
+/// [`ReadySubscription`](crate::ReadySubscription).  The optional
+/// `fallback` will be rendered while waiting, and the optional `name`
+/// allows the `Ready` provided by the nearest `SyncSsr` with the same
+/// name be waited on instead, as per [`Ready::handle_named`].
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_sync_ssr::component::{SyncSsr, WaitReady};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let (title, set_title) = signal(String::new());
+///     view! {
+///         <SyncSsr>
+///             <WaitReady fallback=|| "Loading...">
+///                 // This will see the title set below under SSR.
+///                 <h1>{move || title.get()}</h1>
+///             </WaitReady>
+///             {move || set_title.set("Hello world!".to_string())}
+///         </SyncSsr>
+///     }
+/// }
+/// ```
+///
+/// Under CSR, no waiting will happen.
+#[component]
+pub fn WaitReady(
+    children: ChildrenFn,
+    #[prop(optional, into)] fallback: ViewFnOnce,
+    #[prop(optional, into)] name: Option<Oco<'static, str>>,
+) -> impl IntoView {
+    let handle = match name {
+        Some(name) => Ready::handle_named(name),
+        None => Ready::handle(),
     };
+    wait_then_render(handle, children, fallback)
+}
 
-    #[cfg(feature = "ssr")]
-    let result = view! {
-        <Provider value=ready>
-            {children()}
-            {exit}
-        </Provider>
-    };
+/// This component waits for the provided [`CoReady`] before rendering
+/// its children.
+///
+/// This is the counterpart to [`WaitReady`], where rather than waiting
+/// on a [`Ready`], the wait happens on the provided `CoReady` as per
+/// [`CoReadySubscription::wait`](crate::CoReadySubscription::wait).
+/// The optional `fallback` will be rendered while waiting.
+///
+/// Under CSR, no waiting will happen.
+#[component]
+pub fn WaitCoReady(
+    ready: CoReady,
+    children: ChildrenFn,
+    #[prop(optional, into)] fallback: ViewFnOnce,
+) -> impl IntoView {
+    wait_then_render(ready, children, fallback)
+}
 
-    #[cfg(not(feature = "ssr"))]
-    let result = view! {
-        {children()}
-        {}
-    };
+trait Waitable: Clone + Send + Sync + 'static {
+    fn wait(&self) -> impl std::future::Future<Output = ()> + Send + 'static;
+}
+
+impl Waitable for ReadyHandle {
+    fn wait(&self) -> impl std::future::Future<Output = ()> + Send + 'static {
+        self.subscribe().wait()
+    }
+}
+
+impl Waitable for CoReady {
+    fn wait(&self) -> impl std::future::Future<Output = ()> + Send + 'static {
+        self.subscribe().wait()
+    }
+}
 
-    result
+fn wait_then_render(
+    ready: impl Waitable,
+    children: ChildrenFn,
+    fallback: ViewFnOnce,
+) -> impl IntoView {
+    view! {
+        <Suspense fallback>{
+            move || {
+                let wait = ready.wait();
+                let children = children.clone();
+                Suspend::new(async move {
+                    wait.await;
+                    children()
+                })
+            }
+        }</Suspense>
+    }
 }
 
 /// This component provides the [`CoReadyCoordinator`] context to its
@@ -137,6 +256,10 @@ pub fn SyncSsr(children: Children) -> impl IntoView {
 /// This enables the correct processing order to ensure that the values
 /// to be provided by the resource is provided after waiting correctly.
 ///
+/// An optional [`SyncObserver`] may be provided, which will be used in
+/// place of the one that may be provided as a context by every
+/// [`CoReady`](crate::CoReady) registered to the `CoReadyCoordinator`.
+///
 /// The following represents typical usage.
 ///
 /// ```
@@ -220,35 +343,148 @@ pub fn SyncSsr(children: Children) -> impl IntoView {
 /// #     let _ = view! { <App/> }.to_html();
 /// # });
 /// ```
+///
+/// Should the contexts require some value that may only be acquired
+/// asynchronously, such as the permissions of the current user from a
+/// server function, an [`AsyncSetup`] may be provided through the
+/// optional `async_setup` prop.  The children are rendered right away
+/// without waiting on it, but under SSR every [`CoReady`] in the scope
+/// will wait for it to finish before it may be released, so it may
+/// write the defaults to the contexts provided by the `setup`.  It is
+/// not run under CSR.
 #[component]
-pub fn SyncSsrSignal<SetupFn>(setup: SetupFn, children: Children) -> impl IntoView
+pub fn SyncSsrSignal<SetupFn>(
+    setup: SetupFn,
+    children: Children,
+    #[prop(optional, into)] observer: Option<Arc<dyn SyncObserver>>,
+    #[prop(optional, into)] async_setup: Option<AsyncSetup>,
+) -> impl IntoView
 where
     SetupFn: FnOnce() + Clone + Send + 'static,
 {
-    #[cfg(feature = "ssr")]
-    let coord = CoReadyCoordinator::new();
+    SyncScope {
+        observer,
+        async_setup,
+        ..SyncScope::new().ready(false).setup(setup)
+    }
+    .render(children)
+}
 
-    #[cfg(feature = "ssr")]
-    let exit = {
-        let coord = coord.clone();
-        move || coord.notify()
-    };
+/// The asynchronous setup for [`SyncSsrSignal`], which may be created
+/// from any `FnOnce` that returns a `Future`.
+///
+/// The closure is called after the `setup`, and the `Future` is run with
+/// the reactive owner of the `SyncSsrSignal`, so the contexts provided by
+/// the `setup` may be used inside either.
+///
+/// This is only run under SSR, as its purpose is to hold the readers in
+/// the scope until it is done.  The values it writes to the contexts are
+/// then serialized along with the page through the resources that read
+/// them, so there is nothing to be waited on when hydrating; should the
+/// values be needed under CSR, they should be fetched by the `setup` or
+/// the components themselves.
+///
+/// ```
+/// # #[cfg(feature = "portlet")]
+/// # {
+/// # use leptos::prelude::*;
+/// # use leptos_sync_ssr::{component::SyncSsrSignal, portlet::PortletCtx};
+/// #
+/// # #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+/// # struct Nav(Vec<String>);
+/// #
+/// # async fn get_permissions() -> Vec<String> {
+/// #     vec![]
+/// # }
+/// #
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <SyncSsrSignal
+///             setup={|| PortletCtx::<Nav>::provide()}
+///             async_setup={|| async {
+///                 let permissions = get_permissions().await;
+///                 PortletCtx::<Nav>::expect()
+///                     .inner_write_signal()
+///                     .set(Some(Nav(permissions)));
+///             }}
+///         >
+///             <p>"Other components go here."</p>
+///         </SyncSsrSignal>
+///     }
+/// }
+/// # }
+/// ```
+// Never called under CSR, as per the above.
+#[cfg_attr(not(feature = "ssr"), allow(dead_code))]
+pub struct AsyncSetup(Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send>);
 
-    #[cfg(feature = "ssr")]
-    let result = view! {
-        <Provider value=coord>
-            {setup()}
-            {children()}
-            {exit}
-        </Provider>
-    };
+#[cfg(feature = "ssr")]
+impl AsyncSetup {
+    // The closure is only called once the returned sender is dropped, which
+    // is done after the `setup`, such that it may expect the contexts that
+    // were provided even if a resource polls the future from the `setup`.
+    fn run(self) -> (ReadyFuture, oneshot::Sender<()>) {
+        let (start, started) = oneshot::channel();
+        let fut = async move {
+            let _ = started.await;
+            (self.0)().await
+        };
+        (ScopedFuture::new(fut).boxed().shared(), start)
+    }
+}
 
-    #[cfg(not(feature = "ssr"))]
-    let result = view! {
-        {setup()}
-        {children()}
-        {}
-    };
+impl<F, Fut> From<F> for AsyncSetup
+where
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Box::new(move || f().boxed()))
+    }
+}
+
+impl std::fmt::Debug for AsyncSetup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncSetup").finish_non_exhaustive()
+    }
+}
 
-    result
+/// This component declares the writers that may be rendered by the
+/// route it is placed in, to the [`CoReadyCoordinator`](
+/// crate::CoReadyCoordinator) provided by the nearest [`SyncSsrSignal`].
+///
+/// Typically, the readers of a portlet (or any named [`CoReady`]) will
+/// wait until every child of `SyncSsrSignal` is done rendering, even if
+/// the route that was matched will never write to it.  Placing this at
+/// the top of the view of a route will have the coordinator release the
+/// readers of every named `CoReady` not in `names` right away, as per
+/// [`CoReadyCoordinator::declare_writers`](
+/// crate::CoReadyCoordinator::declare_writers), which also documents what
+/// should be declared.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_sync_ssr::component::DeclareWriters;
+///
+/// #[component]
+/// fn ArticlePage() -> impl IntoView {
+///     view! {
+///         // Only the breadcrumbs may be written by this route; readers of
+///         // every other named resource will not be waiting for this page.
+///         <DeclareWriters names=vec!["breadcrumbs".into()] />
+///         // the components writing to the breadcrumbs...
+///     }
+/// }
+/// ```
+///
+/// Under CSR, this renders nothing and does nothing.
+#[component]
+pub fn DeclareWriters(names: Vec<Oco<'static, str>>) -> impl IntoView {
+    #[cfg(feature = "ssr")]
+    if let Some(coord) = use_context::<CoReadyCoordinator>() {
+        coord.declare_writers(names);
+    }
+    #[cfg(not(feature = "ssr"))]
+    let _ = names;
 }
@@ -0,0 +1,445 @@
+use std::{sync::Arc, time::Duration};
+
+use leptos::{children::Children, component, oco::Oco, prelude::IntoAny, view, IntoView};
+
+use super::AsyncSetup;
+use crate::observer::SyncObserver;
+
+#[cfg(feature = "ssr")]
+mod ssr {
+    pub(super) use crate::ready::ReadyScopes;
+    pub use crate::{ready::CoReadyCoordinator, Ready};
+    pub use leptos::{
+        context::provide_context, reactive::owner::Owner, tachys::reactive_graph::OwnedView,
+        task::spawn,
+    };
+}
+
+#[cfg(feature = "ssr")]
+use ssr::*;
+
+/// How a [`SyncScope`] completes once everything it encloses is done
+/// rendering.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ScopeCompletion {
+    /// Complete the [`Ready`](crate::Ready) and notify the
+    /// [`CoReadyCoordinator`](crate::CoReadyCoordinator), such that the
+    /// subscriptions without outstanding writers stop waiting.
+    #[default]
+    Exit,
+    /// As per `Exit`, except the `CoReadyCoordinator` is [sealed](
+    /// crate::CoReadyCoordinatorHandle::seal), such that the writers
+    /// acquired afterwards are reported as late.
+    Seal,
+}
+
+/// The synchronous setup for a [`SyncScope`], which may be created from
+/// any `FnOnce()`.
+pub struct ScopeSetup(Box<dyn FnOnce() + Send>);
+
+impl<F> From<F> for ScopeSetup
+where
+    F: FnOnce() + Send + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Box::new(f))
+    }
+}
+
+impl std::fmt::Debug for ScopeSetup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScopeSetup").finish_non_exhaustive()
+    }
+}
+
+/// The builder for a scope of synchronized SSR, which is what the
+/// [`SyncSsrScope`] component along with the [`SyncSsr`](super::SyncSsr)
+/// and [`SyncSsrSignal`](super::SyncSsrSignal) components are built on.
+///
+/// By default, both the [`Ready`](crate::Ready) and the
+/// [`CoReadyCoordinator`](crate::CoReadyCoordinator) are provided to the
+/// children, as if they were enclosed by both `SyncSsr` and
+/// `SyncSsrSignal`, and either may be turned off.  All other options
+/// are the same as the props of the components that provide them.
+///
+/// ```
+/// use std::time::Duration;
+/// use leptos::prelude::*;
+/// use leptos_sync_ssr::{component::SyncScope, signal::SsrSignalResource};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     SyncScope::new()
+///         .name("app")
+///         .stall_deadline(Duration::from_secs(5))
+///         .setup(|| provide_context(SsrSignalResource::new(String::new())))
+///         .render(Box::new(|| view! { <p>"Other components go here."</p> }.into_any()))
+/// }
+/// ```
+pub struct SyncScope {
+    pub(super) ready: bool,
+    pub(super) coordinator: bool,
+    pub(super) name: Option<Oco<'static, str>>,
+    pub(super) observer: Option<Arc<dyn SyncObserver>>,
+    pub(super) stall_deadline: Option<Duration>,
+    pub(super) completion: ScopeCompletion,
+    pub(super) setup: Option<ScopeSetup>,
+    pub(super) async_setup: Option<AsyncSetup>,
+}
+
+impl SyncScope {
+    /// A new scope that provides both the `Ready` and the
+    /// `CoReadyCoordinator`, without any other options set.
+    pub fn new() -> Self {
+        Self {
+            ready: true,
+            coordinator: true,
+            name: None,
+            observer: None,
+            stall_deadline: None,
+            completion: ScopeCompletion::default(),
+            setup: None,
+            async_setup: None,
+        }
+    }
+
+    /// Whether the [`Ready`](crate::Ready) is provided, as per
+    /// [`SyncSsr`](super::SyncSsr).
+    pub fn ready(mut self, ready: bool) -> Self {
+        self.ready = ready;
+        self
+    }
+
+    /// Whether the [`CoReadyCoordinator`](crate::CoReadyCoordinator) is
+    /// provided, as per [`SyncSsrSignal`](super::SyncSsrSignal).
+    pub fn coordinator(mut self, coordinator: bool) -> Self {
+        self.coordinator = coordinator;
+        self
+    }
+
+    /// The name of the `Ready`, as per the `name` of `SyncSsr`.
+    pub fn name(mut self, name: impl Into<Oco<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The observer for all the primitives provided, in place of the
+    /// one that may be provided as a context.
+    pub fn observer(mut self, observer: Arc<dyn SyncObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// How long a [`ReadyGuard`](crate::ReadyGuard) may be held before
+    /// it is reported as stalled, as per the `stall_deadline` of
+    /// `SyncSsr`.
+    pub fn stall_deadline(mut self, stall_deadline: Duration) -> Self {
+        self.stall_deadline = Some(stall_deadline);
+        self
+    }
+
+    /// How the scope completes once its children are done rendering.
+    pub fn completion(mut self, completion: ScopeCompletion) -> Self {
+        self.completion = completion;
+        self
+    }
+
+    /// The setup that provides the contexts to the children, as per the
+    /// `setup` of `SyncSsrSignal`.
+    pub fn setup(mut self, setup: impl Into<ScopeSetup>) -> Self {
+        self.setup = Some(setup.into());
+        self
+    }
+
+    /// The asynchronous setup, as per the `async_setup` of
+    /// `SyncSsrSignal`.  Should the `Ready` be provided, it will also be
+    /// held until this is done.
+    pub fn async_setup(mut self, async_setup: impl Into<AsyncSetup>) -> Self {
+        self.async_setup = Some(async_setup.into());
+        self
+    }
+
+    /// Enter this scope on the current reactive owner, providing the
+    /// primitives and running the setups as `render` would, returning the
+    /// [`SyncScopeHandle`] through which the scope is to be completed.
+    ///
+    /// This allows the synchronization to be used in view code that is not
+    /// enclosed by any of the components, where the handle must be
+    /// [completed](SyncScopeHandle::complete) once everything that may
+    /// write has been rendered, otherwise the readers will be left waiting.
+    ///
+    /// ```
+    /// use leptos::prelude::*;
+    /// use leptos_sync_ssr::component::SyncScope;
+    ///
+    /// #[component]
+    /// fn Layout(children: Children) -> impl IntoView {
+    ///     let handle = SyncScope::new().name("layout").enter();
+    ///     view! {
+    ///         <main>{children()}</main>
+    ///         // rendered last, after everything in `main`.
+    ///         {move || handle.complete()}
+    ///     }
+    /// }
+    /// ```
+    pub fn enter(self) -> SyncScopeHandle {
+        self.enter_inner().0
+    }
+
+    // Also returns what the setup returned, if any, for `render` to give
+    // it a place in the view.
+    fn enter_inner(self) -> (SyncScopeHandle, Option<()>) {
+        let Self {
+            ready,
+            coordinator,
+            name,
+            observer,
+            stall_deadline,
+            completion,
+            setup,
+            async_setup,
+        } = self;
+
+        #[cfg(feature = "ssr")]
+        let result = {
+            let ready = ready.then(|| {
+                let ready = Ready::new(name, observer.clone());
+                ready.set_stall_deadline(stall_deadline);
+                ready
+            });
+            let coordinator = coordinator.then(|| CoReadyCoordinator::new(observer));
+
+            if let Some(ready) = &ready {
+                let scopes = ReadyScopes::extend(ready);
+                provide_context(ready.clone());
+                provide_context(scopes);
+            }
+            // The gate is set before the setup so the resources it creates
+            // will wait on it, while its closure is only called after the
+            // setup, with this owner and so the contexts provided by it.
+            let (gate, start) = async_setup.map(AsyncSetup::run).unzip();
+            if let (Some(gate), Some(coordinator)) = (&gate, &coordinator) {
+                coordinator.set_gate(gate.clone());
+            }
+            if let Some(coordinator) = &coordinator {
+                provide_context(coordinator.clone());
+            }
+            let hold = gate
+                .as_ref()
+                .filter(|_| ready.is_some())
+                .map(|_| Ready::hold());
+            let setup = setup.map(|setup| (setup.0)());
+            drop(start);
+            // Driven to completion even if nothing in the scope waits.
+            if let Some(gate) = gate {
+                spawn(async move {
+                    gate.await;
+                    drop(hold);
+                });
+            }
+
+            let handle = SyncScopeHandle {
+                ready,
+                coordinator,
+                completion,
+            };
+            (handle, setup)
+        };
+
+        #[cfg(not(feature = "ssr"))]
+        let result = {
+            let _ = (
+                ready,
+                coordinator,
+                name,
+                observer,
+                stall_deadline,
+                completion,
+                // Only run under SSR, as per `AsyncSetup`.
+                async_setup,
+            );
+            let setup = setup.map(|setup| (setup.0)());
+            (SyncScopeHandle {}, setup)
+        };
+
+        result
+    }
+
+    /// Render the children inside this scope.
+    pub fn render(self, children: Children) -> impl IntoView {
+        #[cfg(feature = "ssr")]
+        let result = {
+            let owner = Owner::current()
+                .expect("no current reactive Owner found")
+                .child();
+            let children = owner.with(|| {
+                let (handle, setup) = self.enter_inner();
+                let exit = move || handle.complete();
+                // Without a setup there is no slot for it, such that the
+                // markup of `SyncSsr` is left as it was.
+                match setup {
+                    Some(setup) => view! {
+                        {setup}
+                        {children()}
+                        {exit}
+                    }
+                    .into_any(),
+                    None => view! {
+                        {children()}
+                        {exit}
+                    }
+                    .into_any(),
+                }
+            });
+            OwnedView::new_with_owner(children, owner)
+        };
+
+        #[cfg(not(feature = "ssr"))]
+        let result = {
+            let (_, setup) = self.enter_inner();
+            match setup {
+                Some(setup) => view! {
+                    {setup}
+                    {children()}
+                    {}
+                }
+                .into_any(),
+                None => view! {
+                    {children()}
+                    {}
+                }
+                .into_any(),
+            }
+        };
+
+        result
+    }
+}
+
+/// The handle to a scope entered through [`SyncScope::enter`], through
+/// which it is completed.
+///
+/// *Under CSR*, there is nothing to complete, and this does nothing.
+#[derive(Clone)]
+#[must_use = "the scope is only completed through this handle"]
+pub struct SyncScopeHandle {
+    #[cfg(feature = "ssr")]
+    ready: Option<Ready>,
+    #[cfg(feature = "ssr")]
+    coordinator: Option<CoReadyCoordinator>,
+    #[cfg(feature = "ssr")]
+    completion: ScopeCompletion,
+}
+
+impl SyncScopeHandle {
+    /// Notify the [`CoReadyCoordinator`](crate::CoReadyCoordinator), such
+    /// that the subscriptions without outstanding writers stop waiting,
+    /// without completing the scope.
+    pub fn notify(&self) {
+        #[cfg(feature = "ssr")]
+        if let Some(coordinator) = &self.coordinator {
+            coordinator.notify();
+        }
+    }
+
+    /// Complete the scope as per its [`ScopeCompletion`].
+    ///
+    /// The coordinator is notified before the [`Ready`](crate::Ready) is
+    /// completed, as would be the case with `SyncSsrSignal` nested in
+    /// `SyncSsr`.
+    pub fn complete(&self) {
+        #[cfg(feature = "ssr")]
+        {
+            if let Some(coordinator) = &self.coordinator {
+                match self.completion {
+                    ScopeCompletion::Exit => coordinator.notify(),
+                    ScopeCompletion::Seal => coordinator.seal(),
+                }
+            }
+            if let Some(ready) = &self.ready {
+                ready.exit();
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for SyncScopeHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncScopeHandle").finish_non_exhaustive()
+    }
+}
+
+impl std::fmt::Debug for SyncScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncScope")
+            .field("ready", &self.ready)
+            .field("coordinator", &self.coordinator)
+            .field("name", &self.name)
+            .field("stall_deadline", &self.stall_deadline)
+            .field("completion", &self.completion)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for SyncScope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A single component that provides any of the primitives provided by
+/// [`SyncSsr`](super::SyncSsr) and [`SyncSsrSignal`](super::SyncSsrSignal),
+/// which would otherwise have to be nested to provide both.
+///
+/// Both the [`Ready`](crate::Ready) and the [`CoReadyCoordinator`](
+/// crate::CoReadyCoordinator) are provided by default, where either may
+/// be turned off through the `ready` and `coordinator` props.  All other
+/// props are optional and are as per [`SyncScope`], which may be used
+/// instead to set all the options outside of a `view!`.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_sync_ssr::{
+///     component::{ScopeCompletion, SyncSsrScope},
+///     signal::SsrSignalResource,
+/// };
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <SyncSsrScope
+///             name="app"
+///             completion=ScopeCompletion::Seal
+///             setup=|| provide_context(SsrSignalResource::new(String::new()))
+///         >
+///             <p>"Other components go here."</p>
+///         </SyncSsrScope>
+///     }
+/// }
+/// ```
+#[allow(clippy::too_many_arguments)]
+#[component]
+pub fn SyncSsrScope(
+    children: Children,
+    #[prop(default = true)] ready: bool,
+    #[prop(default = true)] coordinator: bool,
+    #[prop(optional, into)] setup: Option<ScopeSetup>,
+    #[prop(optional, into)] async_setup: Option<AsyncSetup>,
+    #[prop(optional, into)] name: Option<Oco<'static, str>>,
+    #[prop(optional, into)] observer: Option<Arc<dyn SyncObserver>>,
+    #[prop(optional, into)] stall_deadline: Option<Duration>,
+    #[prop(optional)] completion: ScopeCompletion,
+) -> impl IntoView {
+    SyncScope {
+        ready,
+        coordinator,
+        name,
+        observer,
+        stall_deadline,
+        completion,
+        setup,
+        async_setup,
+    }
+    .render(children)
+}
@@ -152,6 +152,7 @@
 )]
 
 pub mod component;
+pub mod observer;
 #[cfg(feature = "portlet")]
 pub mod portlet;
 mod ready;
@@ -161,5 +162,6 @@ pub mod signal;
 mod tests;
 
 pub use ready::{
-    CoReady, CoReadyCoordinator, CoReadySubscription, Ready, ReadyHandle, ReadySubscription,
+    CoReady, CoReadyCoordinator, CoReadyCoordinatorHandle, CoReadySubscription, Ready, ReadyFuture,
+    ReadyGuard, ReadyHandle, ReadyState, ReadySubscription, SealedError,
 };
@@ -0,0 +1,178 @@
+//! Provides the [`SyncObserver`] hook for observing synchronization events.
+//!
+//! The locking primitives provided by this crate may report what they are
+//! doing under SSR to an observer, such that metrics (e.g. how long readers
+//! have been blocked waiting for some value) may be collected without this
+//! crate having to depend on any particular metrics library.  An observer
+//! may be provided as a context through [`provide_sync_observer`], or be
+//! passed directly to the [`SyncSsr`](crate::component::SyncSsr) and
+//! [`SyncSsrSignal`](crate::component::SyncSsrSignal) components through
+//! their `observer` prop, where the latter takes precedence.
+//!
+//! Given that a new observer may be provided for every request, this also
+//! allow the collection of per-request statistics.  The following shows an
+//! observer that simply count the number of [`CoReady`](crate::CoReady)
+//! created:
+//!
+//! ```
+//! use std::sync::{
+//!     atomic::{AtomicUsize, Ordering},
+//!     Arc,
+//! };
+//! use leptos::prelude::*;
+//! use leptos_sync_ssr::{component::SyncSsrSignal, observer::SyncObserver};
+//!
+//! #[derive(Default)]
+//! struct CoReadyCounter(AtomicUsize);
+//!
+//! impl SyncObserver for CoReadyCounter {
+//!     fn co_ready_created(&self) {
+//!         self.0.fetch_add(1, Ordering::Relaxed);
+//!     }
+//! }
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     let counter: Arc<dyn SyncObserver> = Arc::new(CoReadyCounter::default());
+//!     view! {
+//!         <SyncSsrSignal setup=|| () observer=counter>
+//!             <p>"Other components go here."</p>
+//!         </SyncSsrSignal>
+//!     }
+//! }
+//! ```
+//!
+//! Under CSR, no events will be reported as there are no waiting done.
+//!
+//! A ready made observer, [`ServerTiming`], is also provided under SSR for
+//! reporting the wait times through the `Server-Timing` response header.
+
+use std::{panic::Location, sync::Arc, time::Duration};
+
+use leptos::{context::provide_context, oco::Oco};
+
+#[cfg(feature = "ssr")]
+mod server_timing;
+#[cfg(feature = "ssr")]
+pub use server_timing::ServerTiming;
+
+/// A hook that receives the synchronization events under SSR.
+///
+/// All methods have a default no-op implementation, such that only the
+/// events of interest need to be implemented.
+pub trait SyncObserver: Send + Sync {
+    /// Invoked when a [`ReadySubscription`](crate::ReadySubscription) or
+    /// a [`CoReadySubscription`](crate::CoReadySubscription) finishes
+    /// waiting, with the details of that wait.
+    fn wait(&self, _event: WaitEvent) {}
+
+    /// Invoked when a new [`CoReady`](crate::CoReady) is created and
+    /// registered to its [`CoReadyCoordinator`](crate::CoReadyCoordinator).
+    fn co_ready_created(&self) {}
+
+    /// Invoked at most once for every [`CoReady`](crate::CoReady) when
+    /// it is released, with the reason of the release.
+    fn co_ready_released(&self, _release: Release) {}
+
+    /// Invoked when a writer is acquired from a [`CoReady`](crate::CoReady)
+    /// after its [`CoReadyCoordinator`](crate::CoReadyCoordinator) was
+    /// [sealed](crate::CoReadyCoordinatorHandle::seal), with the name of
+    /// that `CoReady`, if one was assigned.
+    fn late_writer(&self, _name: Option<Oco<'static, str>>) {}
+
+    /// Invoked when a [`ReadyGuard`](crate::ReadyGuard) is still held
+    /// after the `stall_deadline` of its [`SyncSsr`](
+    /// crate::component::SyncSsr), with the details of that guard.
+    fn ready_stalled(&self, _event: StallEvent) {}
+}
+
+// This allows an observer to be provided while a reference to it is kept
+// for the retrieval of whatever it may have collected.
+impl<T: SyncObserver + ?Sized> SyncObserver for Arc<T> {
+    fn wait(&self, event: WaitEvent) {
+        (**self).wait(event)
+    }
+
+    fn co_ready_created(&self) {
+        (**self).co_ready_created()
+    }
+
+    fn co_ready_released(&self, release: Release) {
+        (**self).co_ready_released(release)
+    }
+
+    fn late_writer(&self, name: Option<Oco<'static, str>>) {
+        (**self).late_writer(name)
+    }
+
+    fn ready_stalled(&self, event: StallEvent) {
+        (**self).ready_stalled(event)
+    }
+}
+
+/// The details of a completed wait.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct WaitEvent {
+    /// The kind of subscription that was waiting.
+    pub kind: WaitKind,
+    /// The name of the underlying [`Ready`](crate::Ready) or
+    /// [`CoReady`](crate::CoReady), if one was assigned.
+    pub name: Option<Oco<'static, str>>,
+    /// The duration the subscription spent waiting.
+    pub duration: Duration,
+}
+
+/// The details of a [`ReadyGuard`](crate::ReadyGuard) that was held past
+/// the `stall_deadline`.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct StallEvent {
+    /// The name of the [`Ready`](crate::Ready) being held, if one was
+    /// assigned.
+    pub name: Option<Oco<'static, str>>,
+    /// Where the guard was acquired.
+    pub location: &'static Location<'static>,
+    /// The deadline the guard was held past.
+    pub deadline: Duration,
+}
+
+/// The kind of subscription that reported a [`WaitEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WaitKind {
+    /// The wait happened in [`ReadySubscription::wait`](
+    /// crate::ReadySubscription::wait).
+    Ready,
+    /// The wait happened in [`CoReadySubscription::wait`](
+    /// crate::CoReadySubscription::wait).
+    CoReady,
+}
+
+/// The reason for the release of a [`CoReady`](crate::CoReady).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Release {
+    /// Released by a writer, either through a write to the signal or
+    /// by the dropping of the writer.
+    Write,
+    /// Released by the [`CoReadyCoordinator`](crate::CoReadyCoordinator)
+    /// as there were no outstanding writers when it notified.
+    Notify,
+    /// Released early by the [`CoReadyCoordinator`](
+    /// crate::CoReadyCoordinator) as it was not among the writers that
+    /// were declared, and there were no outstanding writers.
+    Undeclared,
+    /// Released by the [`CoReadyCoordinator`](crate::CoReadyCoordinator)
+    /// as it was [sealed](crate::CoReadyCoordinatorHandle::seal), and
+    /// there were no outstanding writers.
+    Sealed,
+}
+
+/// Provide the observer as a context to the current reactive owner.
+///
+/// The primitives created under the current reactive owner or its
+/// descendants will report to this observer, unless they are enclosed
+/// in a component that was passed an observer explicitly.
+pub fn provide_sync_observer(observer: impl SyncObserver + 'static) {
+    provide_context::<Arc<dyn SyncObserver>>(Arc::new(observer));
+}
@@ -0,0 +1,131 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use leptos::oco::Oco;
+
+use super::{provide_sync_observer, SyncObserver, WaitEvent, WaitKind};
+
+type Entry = (Oco<'static, str>, Duration);
+
+/// A [`SyncObserver`] that collects the wait times for the generation of
+/// the `Server-Timing` response header.
+///
+/// Every [`SyncSsr`](crate::component::SyncSsr) region given a `name`
+/// and every [`SsrSignalResource`](crate::signal::SsrSignalResource)
+/// created through [`new_named`](
+/// crate::signal::SsrSignalResource::new_named) will be reported as its
+/// own entry, with the longest time any of its readers were blocked
+/// as the duration.  Unnamed regions and resources are aggregated into
+/// the `sync-ssr` and `sync-ssr-signal` entries respectively.
+///
+/// Typical usage is to [provide](ServerTiming::provide) this as a
+/// context while setting up the rendering of the request, and then
+/// have the header be written once the readers are done waiting.  Do
+/// note that the response headers are sent along with the first chunk
+/// of the response, so the header will only have the complete values
+/// when the entire response is rendered before it is sent, e.g. when
+/// the route is using `SsrMode::Async`.
+///
+/// ```
+/// use leptos::prelude::*;
+/// use leptos_sync_ssr::{component::SyncSsr, observer::ServerTiming};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let timing = ServerTiming::provide();
+///     view! {
+///         <SyncSsr name="breadcrumbs">
+///             <p>"Other components go here."</p>
+///         </SyncSsr>
+///         {move || {
+///             // Write the header with `leptos_axum` with the `axum` feature,
+///             // or otherwise make use of `timing.header_value()`.
+///             #[cfg(feature = "axum")]
+///             timing.write_header();
+///         }}
+///     }
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ServerTiming {
+    inner: Arc<Mutex<Vec<Entry>>>,
+}
+
+impl SyncObserver for ServerTiming {
+    fn wait(&self, event: WaitEvent) {
+        let name = event.name.unwrap_or_else(|| match event.kind {
+            WaitKind::Ready => "sync-ssr".into(),
+            WaitKind::CoReady => "sync-ssr-signal".into(),
+        });
+        let mut entries = self.inner.lock().expect("mutex not panicked");
+        match entries.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, duration)) => *duration = (*duration).max(event.duration),
+            None => entries.push((name, event.duration)),
+        }
+    }
+}
+
+impl ServerTiming {
+    /// Create a new `ServerTiming` and provide it as the [`SyncObserver`]
+    /// context to the current reactive owner, returning the `ServerTiming`
+    /// such that the collected entries may be accessed.
+    pub fn provide() -> Self {
+        let result = Self::default();
+        provide_sync_observer(result.clone());
+        result
+    }
+
+    /// Returns the collected entries, with the duration being the longest
+    /// a reader was blocked, in the order they were first reported.
+    pub fn entries(&self) -> Vec<(Oco<'static, str>, Duration)> {
+        self.inner.lock().expect("mutex not panicked").clone()
+    }
+
+    /// Returns the value for the `Server-Timing` header for the entries
+    /// that have been collected thus far.
+    ///
+    /// Characters in the names that are not permitted in the header are
+    /// replaced with `_`.
+    pub fn header_value(&self) -> String {
+        self.entries()
+            .iter()
+            .map(|(name, duration)| {
+                let name = name
+                    .chars()
+                    .map(|c| {
+                        if c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c) {
+                            c
+                        } else {
+                            '_'
+                        }
+                    })
+                    .collect::<String>();
+                format!("{name};dur={}", duration.as_secs_f64() * 1000.0)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Write the `Server-Timing` header through the `ResponseOptions`
+    /// provided by `leptos_axum`, replacing any existing value.
+    ///
+    /// Nothing will be written if no entries were collected or if the
+    /// `ResponseOptions` is not available as a context.
+    #[cfg(feature = "axum")]
+    pub fn write_header(&self) {
+        use leptos::{context::use_context, server_fn::http_export::header};
+
+        let value = self.header_value();
+        if value.is_empty() {
+            return;
+        }
+        if let (Some(response), Ok(value)) = (
+            use_context::<leptos_axum::ResponseOptions>(),
+            header::HeaderValue::from_str(&value),
+        ) {
+            response.insert_header(header::HeaderName::from_static("server-timing"), value);
+        }
+    }
+}
@@ -17,20 +17,40 @@
 //! under SSR.  Naturally, a [`SyncSsrSignal`](crate::component::SyncSsrSignal)
 //! must be placed in a higher level of the view tree before `PortletCtx` may
 //! be [provided](PortletCtx::provide) as a context.
+//!
+//! A `Copy` variant, [`StoredPortletCtx`], is also provided for when the
+//! context is to be captured by value in closures, and the portlets that
+//! were provided with a default value may be read as the value itself
+//! through [`DefaultedPortletCtx`].
+//!
+//! For portlets contributed by modules at runtime, where the layout has no
+//! knowledge of their types, the type-erased [`PortletRegistry`] along with
+//! the [`<PortletRegion/>`](PortletRegion) component may be used instead.
+//!
+//! To avoid the portlets flashing empty during CSR navigation, a
+//! [`NavigationSwap`](crate::signal::NavigationSwap) may be provided before
+//! the portlets, such that the values for the new route are swapped in
+//! together.
 
-use std::future::Future;
+use std::{future::Future, sync::Arc};
 
 use leptos::{
+    oco::Oco,
     prelude::{
-        expect_context, provide_context, AnyView, IntoAny, IntoRender, Render, RenderHtml, Suspend,
+        expect_context, provide_context, use_context, AnyView, IntoAny, IntoRender, Render,
+        RenderHtml, Suspend,
     },
-    reactive::{signal::ArcWriteSignal, traits::Set},
-    server::ArcResource,
+    reactive::{owner::ArenaItem, signal::ArcWriteSignal, traits::Set},
+    server::{codee::string::JsonSerdeCodec, ArcResource, Resource},
     suspense::Transition,
     view, IntoView,
 };
 
-use crate::signal::SsrSignalResource;
+use crate::signal::{ResourceCodec, SsrSignalResource, SsrTransaction, SsrTxWriteSignal};
+
+mod registry;
+
+pub use registry::{PortletRegion, PortletRegionProps, PortletRegistry};
 
 /// A generic portlet context.
 ///
@@ -45,20 +65,74 @@ use crate::signal::SsrSignalResource;
 /// Code examples below are modified code snippets from the [`nav_portlet`](
 /// https://github.com/metatoaster/leptos_sync_ssr/tree/main/example/nav_portlet)
 /// example.
-#[derive(Clone, Debug)]
-pub struct PortletCtx<T> {
-    inner: SsrSignalResource<Option<T>>,
+///
+/// The value is serialized for hydration with the [`ResourceCodec`] `Ser`,
+/// which defaults to [`JsonSerdeCodec`], e.g. `PortletCtx<Nav, RkyvCodec>`
+/// would have the `Nav` be serialized through `rkyv` instead.
+pub struct PortletCtx<T, Ser = JsonSerdeCodec> {
+    inner: SsrSignalResource<Option<T>, Ser>,
+    default: Option<Arc<T>>,
+    #[cfg(not(feature = "ssr"))]
+    swap: Option<crate::signal::NavigationSwap>,
+}
+
+impl<T, Ser> Clone for PortletCtx<T, Ser> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            default: self.default.clone(),
+            #[cfg(not(feature = "ssr"))]
+            swap: self.swap.clone(),
+        }
+    }
+}
+
+impl<T, Ser> std::fmt::Debug for PortletCtx<T, Ser> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PortletCtx")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+// The slots provided as a context, where the slots provided by the nearest
+// reactive owner take precedence by being placed at the end.
+struct PortletSlots<T, Ser>(Arc<Vec<PortletSlot<T, Ser>>>);
+
+type PortletSlot<T, Ser> = (Oco<'static, str>, PortletCtx<T, Ser>);
+
+impl<T, Ser> Clone for PortletSlots<T, Ser> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T, Ser> Default for PortletSlots<T, Ser> {
+    fn default() -> Self {
+        Self(Arc::new(Vec::new()))
+    }
+}
+
+impl<T, Ser> PortletSlots<T, Ser> {
+    fn extend(&self, slot: Oco<'static, str>, ctx: PortletCtx<T, Ser>) -> Self {
+        let mut slots = self.0.iter().cloned().collect::<Vec<_>>();
+        slots.push((slot, ctx));
+        Self(Arc::new(slots))
+    }
+
+    fn get(&self, slot: &Oco<'static, str>) -> Option<PortletCtx<T, Ser>> {
+        self.0
+            .iter()
+            .rev()
+            .find(|(name, _)| name == slot)
+            .map(|(_, ctx)| ctx.clone())
+    }
 }
 
-impl<T> PortletCtx<T>
+impl<T, Ser> PortletCtx<T, Ser>
 where
-    T: serde::Serialize
-        + serde::de::DeserializeOwned
-        + Clone
-        + PartialEq
-        + Send
-        + Sync
-        + 'static,
+    T: Clone + PartialEq + Send + Sync + 'static,
+    Ser: ResourceCodec<Option<T>>,
 {
     /// Provide this as a context for a Leptos `App`.
     ///
@@ -143,9 +217,149 @@ where
     /// [`<SyncSsrSignal/>`](crate::component::SyncSsrSignal) component.
     pub fn provide() {
         // TODO ensure the singleton aspect.
-        provide_context(PortletCtx::<T> {
-            inner: SsrSignalResource::new(None),
+        provide_context(Self::new_inner(Self::writer_name(), None));
+    }
+
+    /// Provide this as a context for a Leptos `App`, with a default value.
+    ///
+    /// This is the same as [`provide`](PortletCtx::provide), except the
+    /// portlet will start with the default value rather than `None`, and
+    /// [`clear`](PortletCtx::clear) will reset it back to that default.
+    /// This allows portlets that should always show something, such as
+    /// the default site navigation, to not have to handle the `None` case
+    /// when rendered, as readers will always get a value.
+    ///
+    /// A [`DefaultedPortletCtx`] backed by the same portlet is provided
+    /// as a context alongside, for readers that need the value as `T`.
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found,
+    /// as per [`provide`](PortletCtx::provide).
+    pub fn provide_with(default: T) {
+        let ctx = Self::new_inner(Self::writer_name(), Some(default));
+        provide_context(DefaultedPortletCtx {
+            inner: ctx.clone(),
+            default: ctx.default.clone().expect("provided with a default"),
         });
+        provide_context(ctx);
+    }
+
+    /// Provide this as a context for a Leptos `App`, with the value from
+    /// [`Default::default`] as the default value, as per
+    /// [`provide_with`](PortletCtx::provide_with).
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found,
+    /// as per [`provide`](PortletCtx::provide).
+    pub fn provide_default()
+    where
+        T: Default,
+    {
+        Self::provide_with(T::default())
+    }
+
+    /// Provide this as a context that overrides the portlet provided by
+    /// an outer [`<SyncSsrSignal/>`](crate::component::SyncSsrSignal),
+    /// for as long as the scope this is provided under remains mounted.
+    ///
+    /// Calling [`provide`](PortletCtx::provide) again inside a nested
+    /// `SyncSsrSignal` shadows the outer portlet entirely, such that the
+    /// writers inside the nested scope will never update the reader that
+    /// is rendered by the outer scope.  Instead, this should be called in
+    /// the `setup` of the nested `SyncSsrSignal`, and the writers inside
+    /// will write to a new portlet whose value is then written through to
+    /// the outer portlet, leaving it unchanged if none of them did.
+    ///
+    /// *Under SSR*, a writer to the outer portlet is held until the
+    /// writers of the nested scope are done, so the outer readers will
+    /// wait for them.
+    ///
+    /// *Under CSR*, every change made by the writers of the nested scope
+    /// is written through, and once the nested scope is unmounted, the
+    /// outer portlet reverts to the value it held before the override.
+    /// As that value is not known when the page was hydrated with the
+    /// override already in effect, the outer portlet is instead cleared
+    /// as per [`clear`](PortletCtx::clear) in that case.
+    ///
+    /// Note that the readers of the overriding portlet provided by this
+    /// will only see the values from the writers of the nested scope.
+    ///
+    /// ## Panics
+    /// Panics if `PortletCtx<T>` is not found in the ancestors of the
+    /// current reactive owner, or if the context of type
+    /// `CoReadyCoordinator` is not found as per [`provide`](
+    /// PortletCtx::provide).
+    #[track_caller]
+    pub fn provide_override() {
+        let outer = Self::expect();
+        let inner = Self::new_inner(Self::writer_name(), None);
+        provide_context(inner.clone());
+
+        #[cfg(feature = "ssr")]
+        {
+            let ws = outer.inner.write_only();
+            let resource = inner.inner.read_only();
+            leptos::task::spawn(async move {
+                if let Some(value) = resource.await {
+                    ws.set(Some(value));
+                }
+            });
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use leptos::reactive::{
+                effect::Effect,
+                owner::on_cleanup,
+                traits::{Get, GetUntracked},
+            };
+
+            let signal = inner.inner.inner_read_only();
+            // Having a value from the start means it was hydrated with the
+            // one written through on the server.
+            let previous = match signal.get_untracked() {
+                Some(_) => outer.default.as_deref().cloned(),
+                None => outer.inner.inner_read_only().get_untracked(),
+            };
+            Effect::new({
+                let ws = outer.inner.inner_write_only();
+                move |_| {
+                    if let Some(value) = signal.get() {
+                        ws.set(Some(value));
+                    }
+                }
+            });
+            on_cleanup(move || outer.write(previous));
+        }
+    }
+
+    #[track_caller]
+    fn new_inner(name: Oco<'static, str>, default: Option<T>) -> Self {
+        Self {
+            inner: SsrSignalResource::new_named_with_codec(name, default.clone()),
+            default: default.map(Arc::new),
+            #[cfg(not(feature = "ssr"))]
+            swap: use_context::<crate::signal::NavigationSwap>(),
+        }
+    }
+
+    /// The name of the portlet provided through [`provide`](
+    /// PortletCtx::provide), which is the type name of `T`.
+    ///
+    /// This is the name reported to the [`SyncObserver`](
+    /// crate::observer::SyncObserver), and it may be used to declare
+    /// the portlet as one that may be written to by a route through
+    /// [`DeclareWriters`](crate::component::DeclareWriters).
+    pub fn writer_name() -> Oco<'static, str> {
+        Oco::Borrowed(std::any::type_name::<T>())
+    }
+
+    /// The name of the portlet provided for the named slot through
+    /// [`provide_slot`](PortletCtx::provide_slot), which is the type
+    /// name of `T` followed by `#` and the slot, as per [`writer_name`](
+    /// PortletCtx::writer_name).
+    pub fn slot_writer_name(slot: impl Into<Oco<'static, str>>) -> Oco<'static, str> {
+        Oco::Owned(format!("{}#{}", std::any::type_name::<T>(), slot.into()))
     }
 
     /// Alias for [`expect_context::<PortletCtx<T>>()`](expect_context).
@@ -153,8 +367,79 @@ where
     /// ## Panics
     /// Panics if `PortletCtx<T>` is not found in the current reactive
     /// owner or its ancestors.
-    pub fn expect() -> PortletCtx<T> {
-        expect_context::<PortletCtx<T>>()
+    pub fn expect() -> PortletCtx<T, Ser> {
+        expect_context::<PortletCtx<T, Ser>>()
+    }
+
+    /// Provide this for the named slot as a context for a Leptos `App`.
+    ///
+    /// This allows multiple portlets of the same `T` be provided, such
+    /// as a "left" and a "right" navigation column, where each of them
+    /// may be acquired through [`expect_slot`](PortletCtx::expect_slot)
+    /// and rendered through [`render_slot`](PortletCtx::render_slot)
+    /// using the same slot name.  Each slot has its own underlying
+    /// `SsrSignalResource`, so the locking is the same as the one
+    /// provided through [`provide`](PortletCtx::provide), and providing
+    /// the same slot again under a descendant reactive owner will shadow
+    /// the one provided by its ancestor.
+    ///
+    /// ```
+    /// # use leptos::prelude::*;
+    /// # use leptos_sync_ssr::{component::SyncSsrSignal, portlet::PortletCtx};
+    /// #
+    /// # #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+    /// # struct NavItems;
+    /// # impl IntoRender for NavItems {
+    /// #     type Output = AnyView;
+    /// #     fn into_render(self) -> Self::Output {
+    /// #         ().into_any()
+    /// #     }
+    /// # }
+    /// #
+    /// #[component]
+    /// pub fn App() -> impl IntoView {
+    ///     view! {
+    ///         <SyncSsrSignal setup=|| {
+    ///             <PortletCtx<NavItems>>::provide_slot("left");
+    ///             <PortletCtx<NavItems>>::provide_slot("right");
+    ///         }>
+    ///             <nav>{<PortletCtx<NavItems>>::render_slot("left")}</nav>
+    ///             <nav>{<PortletCtx<NavItems>>::render_slot("right")}</nav>
+    ///         </SyncSsrSignal>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found,
+    /// as per [`provide`](PortletCtx::provide).
+    pub fn provide_slot(slot: impl Into<Oco<'static, str>>) {
+        let slot = slot.into();
+        let ctx = Self::new_inner(Self::slot_writer_name(slot.clone()), None);
+        provide_context(
+            use_context::<PortletSlots<T, Ser>>()
+                .unwrap_or_default()
+                .extend(slot, ctx),
+        );
+    }
+
+    /// Acquire the `PortletCtx<T>` provided for the named slot through
+    /// [`provide_slot`](PortletCtx::provide_slot).
+    ///
+    /// ## Panics
+    /// Panics if no `PortletCtx<T>` is provided for the slot in the
+    /// current reactive owner or its ancestors.
+    #[track_caller]
+    pub fn expect_slot(slot: impl Into<Oco<'static, str>>) -> PortletCtx<T, Ser> {
+        let slot = slot.into();
+        use_context::<PortletSlots<T, Ser>>()
+            .and_then(|slots| slots.get(&slot))
+            .unwrap_or_else(|| {
+                panic!(
+                    "expected a `{}` for the slot {slot:?} to be provided",
+                    std::any::type_name::<Self>()
+                )
+            })
     }
 
     /// Set the portlet with the provided data fetcher.
@@ -289,6 +574,7 @@ where
     ) -> impl IntoView
     where
         Fut: Future<Output = U> + Send + 'static,
+        U: Send + 'static,
     {
         self.inner.update_with(fetcher, updater)
     }
@@ -341,12 +627,47 @@ where
         <T as leptos::prelude::IntoRender>::Output: RenderHtml + Send + 'static,
         Suspend<Option<AnyView>>: RenderHtml + Render,
     {
-        let ctx = expect_context::<PortletCtx<T>>();
+        expect_context::<PortletCtx<T, Ser>>().into_view()
+    }
+
+    /// Render the portlet provided for the named slot, as per
+    /// [`render`](PortletCtx::render).
+    ///
+    /// ## Panics
+    /// Panics if no `PortletCtx<T>` is provided for the slot in the
+    /// current reactive owner or its ancestors.
+    pub fn render_slot(slot: impl Into<Oco<'static, str>>) -> impl IntoView
+    where
+        T: IntoRender,
+        <T as leptos::prelude::IntoRender>::Output: RenderHtml + Send + 'static,
+        Suspend<Option<AnyView>>: RenderHtml + Render,
+    {
+        Self::expect_slot(slot).into_view()
+    }
+
+    fn into_view(self) -> impl IntoView
+    where
+        T: IntoRender,
+        <T as leptos::prelude::IntoRender>::Output: RenderHtml + Send + 'static,
+        Suspend<Option<AnyView>>: RenderHtml + Render,
+    {
+        self.view_with(|value| value.into_render().into_any())
+    }
+
+    fn view_with(self, f: impl Fn(T) -> AnyView + Send + Sync + 'static) -> impl IntoView
+    where
+        Suspend<Option<AnyView>>: RenderHtml + Render,
+    {
+        let ctx = self;
         // The resource must be used and not the underlying `ArcReadSignal`,
         // hydration error results otherwise.
         let resource = ctx.inner.read_only();
+        let default = ctx.default.clone();
+        let f = Arc::new(f);
         let suspend = move || {
             let resource = resource.clone();
+            let default = default.clone();
+            let f = f.clone();
             Suspend::new(async move {
                 // While it is be possible to use the inner `ArcReadSignal`
                 // under CSR, with hydration this can be problematic given
@@ -361,7 +682,10 @@ where
                 //     Some(v) => Some(v),
                 //     None => ctx.inner.read_only().await,
                 // };
-                Some(resource.await?.into_render().into_any())
+                // A `None` written by some writer will be rendered as the
+                // default value, should one be provided.
+                let value = resource.await.or_else(|| default.as_deref().cloned());
+                Some(f(value?))
             })
         };
         view! { <Transition>{move || suspend() }</Transition> }
@@ -373,13 +697,44 @@ where
     /// underlying write signal, which should trigger the re-rendering
     /// through the associated function [`render`](PortletCtx::render).
     /// Given the `None` value, this typically results in nothing being
-    /// rendered, achieving the goal of clearing the portlet.
+    /// rendered, achieving the goal of clearing the portlet.  If this was
+    /// provided with a default value through [`provide_with`](
+    /// PortletCtx::provide_with), that default value will be written
+    /// instead.
     ///
     /// Note that this is typically expected to be used in conjunction
     /// with [`on_cleanup`](leptos::reactive::owner::on_cleanup) under
     /// CSR.  Usage under SSR may lead to unexpected behavior.
+    ///
+    /// Should a [`NavigationSwap`](crate::signal::NavigationSwap) be
+    /// provided as a context at the time this portlet was provided, the
+    /// clearing under CSR is staged such that the current value remains
+    /// until the values from the writers of the next route are swapped in.
     pub fn clear(&self) {
-        self.inner.inner_write_only().set(None);
+        self.write(self.default.as_deref().cloned());
+    }
+
+    // Writes to the underlying signal directly, staged through the
+    // `NavigationSwap` where one was provided.
+    fn write(&self, value: Option<T>) {
+        let ws = self.inner.inner_write_only();
+        #[cfg(not(feature = "ssr"))]
+        if let Some(swap) = &self.swap {
+            swap.stage(move || ws.set(value));
+            return;
+        }
+        ws.set(value);
+    }
+
+    /// Make this portlet part of the [`SsrTransaction`], returning the
+    /// writer that will buffer the value for it until the transaction
+    /// commits, as per [`SsrTransaction::write`].
+    ///
+    /// This allows a component that writes to multiple portlets, such as
+    /// both the navigation and the information about the current entity,
+    /// have the readers of all those portlets be released together.
+    pub fn write_in(&self, tx: &SsrTransaction) -> SsrTxWriteSignal<Option<T>> {
+        tx.write(&self.inner)
     }
 
     /// Acquire the inner `ArcWriteSignal`.
@@ -403,7 +758,248 @@ where
     /// acquire a clone of the resource as per that method.  This is
     /// provided to facilitate more complex rendering requirements, such
     /// as the need to `await` for other resources beyond this one.
-    pub fn inner_resource(&self) -> ArcResource<Option<T>> {
+    pub fn inner_resource(&self) -> ArcResource<Option<T>, Ser> {
         self.inner.read_only()
     }
 }
+
+/// A [`PortletCtx`] that was provided with a default value, through
+/// [`PortletCtx::provide_with`] or [`PortletCtx::provide_default`].
+///
+/// Both of those provide this as a context along with the `PortletCtx`
+/// itself, backed by the same portlet, such that readers that require
+/// the portlet to always have a value may acquire this instead, having
+/// the presence of the default value be checked as this is acquired,
+/// rather than every time the value is read.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_sync_ssr::portlet::DefaultedPortletCtx;
+/// #
+/// # #[derive(Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+/// # struct Title(String);
+/// #
+/// #[component]
+/// pub fn PageTitle() -> impl IntoView {
+///     let ctx = DefaultedPortletCtx::<Title>::expect();
+///     view! {
+///         <h1><Suspense>{move || {
+///             let title = ctx.read();
+///             Suspend::new(async move { title.await.0 })
+///         }}</Suspense></h1>
+///     }
+/// }
+/// ```
+pub struct DefaultedPortletCtx<T, Ser = JsonSerdeCodec> {
+    inner: PortletCtx<T, Ser>,
+    default: Arc<T>,
+}
+
+impl<T, Ser> Clone for DefaultedPortletCtx<T, Ser> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            default: self.default.clone(),
+        }
+    }
+}
+
+impl<T, Ser> std::fmt::Debug for DefaultedPortletCtx<T, Ser> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DefaultedPortletCtx")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T, Ser> DefaultedPortletCtx<T, Ser>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+    Ser: ResourceCodec<Option<T>>,
+{
+    /// Alias for
+    /// [`expect_context::<DefaultedPortletCtx<T>>()`](expect_context).
+    ///
+    /// ## Panics
+    /// Panics if `DefaultedPortletCtx<T>` is not found in the current
+    /// reactive owner or its ancestors, such as when the `PortletCtx<T>`
+    /// was provided without a default value.
+    pub fn expect() -> Self {
+        expect_context::<Self>()
+    }
+
+    /// Returns the underlying [`PortletCtx`], for the writers and the
+    /// rendering.
+    pub fn inner(&self) -> PortletCtx<T, Ser> {
+        self.inner.clone()
+    }
+
+    /// Acquire the value of the portlet as `T`.
+    ///
+    /// The returned future awaits the [`inner_resource`](
+    /// PortletCtx::inner_resource), with a `None` written by any writer
+    /// resolving to the default value, which is the same value that
+    /// [`render`](PortletCtx::render) would render.
+    pub fn read(&self) -> impl Future<Output = T> + Send + 'static {
+        let resource = self.inner.inner_resource();
+        let default = self.default.clone();
+        async move { resource.await.unwrap_or_else(|| default.as_ref().clone()) }
+    }
+}
+
+/// An arena-allocated [`PortletCtx`], which is `Copy` and is disposed
+/// along with the reactive owner it was created under.
+///
+/// This allows the portlet context be captured by value in closures, as
+/// opposed to having to clone a `PortletCtx` into every one of them.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_sync_ssr::portlet::StoredPortletCtx;
+/// #
+/// # #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+/// # struct Nav;
+/// #
+/// #[component]
+/// pub fn AuthorTop() -> impl IntoView {
+///     let nav_ctx = <StoredPortletCtx<Nav>>::expect();
+///     on_cleanup(move || nav_ctx.clear());
+///     view! {
+///         {nav_ctx.set_with(move || async move { Some(Nav) })}
+///     }
+/// }
+/// ```
+///
+/// ## Panics
+/// Accessing any of the methods after the owner was disposed will panic.
+pub struct StoredPortletCtx<T, Ser = JsonSerdeCodec>
+where
+    T: Send + Sync + 'static,
+{
+    inner: ArenaItem<PortletCtx<T, Ser>>,
+    // Created once along with the above, such that every `inner_resource`
+    // returns the same arena-allocated `Resource`.
+    resource: Resource<Option<T>, Ser>,
+}
+
+impl<T, Ser> Clone for StoredPortletCtx<T, Ser>
+where
+    T: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, Ser> Copy for StoredPortletCtx<T, Ser> where T: Send + Sync + 'static {}
+
+impl<T, Ser> std::fmt::Debug for StoredPortletCtx<T, Ser>
+where
+    T: Send + Sync + 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StoredPortletCtx")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T, Ser> From<PortletCtx<T, Ser>> for StoredPortletCtx<T, Ser>
+where
+    T: Send + Sync + 'static,
+    Ser: Send + Sync + 'static,
+{
+    fn from(value: PortletCtx<T, Ser>) -> Self {
+        Self {
+            resource: value.inner.read_only().into(),
+            inner: ArenaItem::new(value),
+        }
+    }
+}
+
+impl<T, Ser> StoredPortletCtx<T, Ser>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+    Ser: ResourceCodec<Option<T>>,
+{
+    /// Acquire the provided [`PortletCtx<T>`] and store it under the
+    /// current reactive owner.
+    ///
+    /// ## Panics
+    /// Panics if `PortletCtx<T>` is not found in the current reactive
+    /// owner or its ancestors.
+    pub fn expect() -> Self {
+        PortletCtx::<T, Ser>::expect().into()
+    }
+
+    /// Acquire the [`PortletCtx<T>`] provided for the named slot and
+    /// store it under the current reactive owner.
+    ///
+    /// ## Panics
+    /// Panics if no `PortletCtx<T>` is provided for the slot in the
+    /// current reactive owner or its ancestors.
+    #[track_caller]
+    pub fn expect_slot(slot: impl Into<Oco<'static, str>>) -> Self {
+        PortletCtx::<T, Ser>::expect_slot(slot).into()
+    }
+
+    /// Returns the stored [`PortletCtx`].
+    #[track_caller]
+    pub fn inner(&self) -> PortletCtx<T, Ser> {
+        self.inner
+            .try_get_value()
+            .expect("StoredPortletCtx accessed after being disposed")
+    }
+
+    /// Set the portlet with the provided data fetcher, as per
+    /// [`PortletCtx::set_with`].
+    #[track_caller]
+    pub fn set_with<Fut>(&self, fetcher: impl Fn() -> Fut + Send + Sync + 'static) -> impl IntoView
+    where
+        Fut: Future<Output = Option<T>> + Send + 'static,
+    {
+        self.inner().set_with(fetcher)
+    }
+
+    /// Update the portlet with the provided data fetcher and the updater
+    /// function, as per [`PortletCtx::update_with`].
+    #[track_caller]
+    pub fn update_with<Fut, U>(
+        &self,
+        fetcher: impl Fn() -> Fut + Send + Sync + 'static,
+        updater: impl Fn(&mut Option<T>, U) + Send + Sync + 'static,
+    ) -> impl IntoView
+    where
+        Fut: Future<Output = U> + Send + 'static,
+        U: Send + 'static,
+    {
+        self.inner().update_with(fetcher, updater)
+    }
+
+    /// Clears the portlet, as per [`PortletCtx::clear`].
+    #[track_caller]
+    pub fn clear(&self) {
+        self.inner().clear()
+    }
+
+    /// Make this portlet part of the [`SsrTransaction`], as per
+    /// [`PortletCtx::write_in`].
+    #[track_caller]
+    pub fn write_in(&self, tx: &SsrTransaction) -> SsrTxWriteSignal<Option<T>> {
+        self.inner().write_in(tx)
+    }
+
+    /// Acquire the inner `ArcWriteSignal`, as per
+    /// [`PortletCtx::inner_write_signal`].
+    #[track_caller]
+    pub fn inner_write_signal(&self) -> ArcWriteSignal<Option<T>> {
+        self.inner().inner_write_signal()
+    }
+
+    /// Acquire the inner resource as an arena-allocated `Resource`, as
+    /// per [`PortletCtx::inner_resource`].
+    #[track_caller]
+    pub fn inner_resource(&self) -> Resource<Option<T>, Ser> {
+        self.resource
+    }
+}
@@ -0,0 +1,270 @@
+use std::{
+    any::Any,
+    fmt::{Debug, Formatter, Result},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, RwLock,
+    },
+};
+
+use leptos::{
+    component,
+    oco::Oco,
+    prelude::{expect_context, provide_context, AnyView, For, IntoAny},
+    reactive::{
+        signal::ArcTrigger,
+        traits::{Notify, Track},
+    },
+    view, IntoView,
+};
+
+use super::PortletCtx;
+
+/// A type-erased registry of portlets, to be rendered together by the
+/// [`<PortletRegion/>`](PortletRegion) component.
+///
+/// This allows portlets of different types be contributed by modules
+/// that the layout rendering them has no knowledge of.  Each portlet is
+/// [registered](PortletRegistry::register) with a key, an order and a
+/// function that renders its value into an `AnyView`, and is backed by
+/// its own [`PortletCtx`], so the locking under SSR is the same as for
+/// a portlet provided through [`PortletCtx::provide`] - the region will
+/// wait on each of them.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_sync_ssr::{
+/// #     component::SyncSsrSignal,
+/// #     portlet::{PortletRegion, PortletRegistry},
+/// # };
+/// #
+/// # #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+/// # struct Weather(String);
+/// #
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <SyncSsrSignal setup=|| {
+///             let registry = PortletRegistry::provide();
+///             // typically done by the setup function of every module.
+///             registry.register("weather", 10, |weather: Weather| {
+///                 view! { <p>{weather.0}</p> }.into_any()
+///             });
+///         }>
+///             <aside><PortletRegion/></aside>
+///             // the writers may acquire the portlet through the registry:
+///             // PortletRegistry::expect().portlet::<Weather>("weather")
+///         </SyncSsrSignal>
+///     }
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct PortletRegistry {
+    // Kept sorted by the order of the entries.
+    entries: Arc<RwLock<Vec<RegistryEntry>>>,
+    next_id: Arc<AtomicUsize>,
+    trigger: ArcTrigger,
+}
+
+#[derive(Clone)]
+struct RegistryEntry {
+    // Unique for every registration, such that the region will only ever
+    // create the view for an entry once.
+    id: usize,
+    key: Oco<'static, str>,
+    order: i32,
+    portlet: Arc<dyn Any + Send + Sync>,
+    view: Arc<dyn Fn() -> AnyView + Send + Sync>,
+}
+
+impl PortletRegistry {
+    /// Create a new `PortletRegistry` and provide it as a context to the
+    /// current reactive owner, returning it for the registration of the
+    /// portlets.
+    pub fn provide() -> Self {
+        let result = Self::default();
+        provide_context(result.clone());
+        result
+    }
+
+    /// Alias for [`expect_context::<PortletRegistry>()`](expect_context).
+    ///
+    /// ## Panics
+    /// Panics if `PortletRegistry` is not found in the current reactive
+    /// owner or its ancestors.
+    pub fn expect() -> Self {
+        expect_context::<Self>()
+    }
+
+    /// Register a portlet of type `T` with the key, to be rendered with
+    /// the provided function in ascending order, where portlets of the
+    /// same order are rendered in the order they were registered.
+    ///
+    /// Returns the newly created [`PortletCtx<T>`] backing the portlet,
+    /// which may also be acquired through [`portlet`](
+    /// PortletRegistry::portlet).  Should a portlet of the same type be
+    /// already registered with the same key, that portlet is returned
+    /// as is, with the provided order and render function ignored, as
+    /// replacing it would leave the readers and writers that already
+    /// acquired it behind.  The key is also used as the name of the
+    /// portlet for the purpose of [`DeclareWriters`](
+    /// crate::component::DeclareWriters).
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found,
+    /// as per [`PortletCtx::provide`], or if a portlet of a different
+    /// type is already registered with the same key.
+    #[track_caller]
+    pub fn register<T>(
+        &self,
+        key: impl Into<Oco<'static, str>>,
+        order: i32,
+        render: impl Fn(T) -> AnyView + Send + Sync + 'static,
+    ) -> PortletCtx<T>
+    where
+        T: serde::Serialize
+            + serde::de::DeserializeOwned
+            + Clone
+            + PartialEq
+            + Send
+            + Sync
+            + 'static,
+    {
+        let key = key.into();
+        let location = std::panic::Location::caller();
+        if let Some(entry) = self.find(&key) {
+            return Self::registered(entry.portlet, &key, location);
+        }
+        // Created without holding onto the lock, as this panics without the
+        // `CoReadyCoordinator`.
+        let ctx = PortletCtx::<T>::new_inner(key.clone(), None);
+        let render = Arc::new(render);
+        let entry = RegistryEntry {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            key: key.clone(),
+            order,
+            portlet: Arc::new(ctx.clone()),
+            view: Arc::new({
+                let ctx = ctx.clone();
+                move || {
+                    let render = render.clone();
+                    ctx.clone().view_with(move |value| render(value)).into_any()
+                }
+            }),
+        };
+        let mut entries = self.entries.write().expect("not poisoned");
+        // Should the key be registered while the lock was not held, that one
+        // is kept as per the above.
+        if let Some(portlet) = entries
+            .iter()
+            .find(|entry| entry.key == key)
+            .map(|entry| entry.portlet.clone())
+        {
+            drop(entries);
+            return Self::registered(portlet, &key, location);
+        }
+        // after the entries of the same order, so the registration order is
+        // kept for those.
+        let at = entries.partition_point(|entry| entry.order <= order);
+        entries.insert(at, entry);
+        drop(entries);
+        self.trigger.notify();
+        ctx
+    }
+
+    // Not to be called while holding onto the lock, should this panic.
+    fn registered<T: 'static>(
+        portlet: Arc<dyn Any + Send + Sync>,
+        key: &Oco<'static, str>,
+        location: &std::panic::Location<'static>,
+    ) -> PortletCtx<T> {
+        portlet
+            .downcast_ref::<PortletCtx<T>>()
+            .cloned()
+            .unwrap_or_else(|| {
+                panic!(
+                    "{location:?} registered the portlet {key:?} already registered with a \
+                     different type"
+                )
+            })
+    }
+
+    /// Remove the portlet registered with the key, returning whether it
+    /// was registered.
+    pub fn unregister(&self, key: impl Into<Oco<'static, str>>) -> bool {
+        let key = key.into();
+        let removed = {
+            let mut entries = self.entries.write().expect("not poisoned");
+            let len = entries.len();
+            entries.retain(|entry| entry.key != key);
+            entries.len() != len
+        };
+        if removed {
+            self.trigger.notify();
+        }
+        removed
+    }
+
+    /// Acquire the [`PortletCtx<T>`] registered with the key.
+    ///
+    /// Returns `None` if nothing is registered with the key, or if the
+    /// portlet registered with the key isn't of the type `T`.
+    pub fn portlet<T: 'static>(&self, key: impl Into<Oco<'static, str>>) -> Option<PortletCtx<T>> {
+        self.find(&key.into())
+            .and_then(|entry| entry.portlet.downcast_ref::<PortletCtx<T>>().cloned())
+    }
+
+    fn find(&self, key: &Oco<'static, str>) -> Option<RegistryEntry> {
+        self.entries
+            .read()
+            .expect("not poisoned")
+            .iter()
+            .find(|entry| entry.key == *key)
+            .cloned()
+    }
+
+    fn entries(&self) -> Vec<RegistryEntry> {
+        self.trigger.track();
+        self.entries.read().expect("not poisoned").clone()
+    }
+}
+
+impl Debug for PortletRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("PortletRegistry")
+            .field(
+                "entries",
+                &self
+                    .entries
+                    .read()
+                    .expect("not poisoned")
+                    .iter()
+                    .map(|entry| (&entry.key, entry.order))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Renders all the portlets registered to the [`PortletRegistry`] that
+/// was provided as a context, in order.
+///
+/// Under SSR, every portlet will wait on its own lock as per
+/// [`PortletCtx::render`].  The view of every portlet is created once,
+/// such that the registration or removal of other portlets will not
+/// cause it to be rendered again.
+///
+/// ## Panics
+/// Panics if `PortletRegistry` is not found in the current reactive
+/// owner or its ancestors.
+#[component]
+pub fn PortletRegion() -> impl IntoView {
+    let registry = PortletRegistry::expect();
+    view! {
+        <For
+            each=move || registry.entries()
+            key=|entry| entry.id
+            children=|entry| (entry.view)()
+        />
+    }
+}
@@ -1,8 +1,32 @@
+use std::future::Future;
+
+use futures::{
+    future::{BoxFuture, Shared},
+    stream, FutureExt, Stream, StreamExt,
+};
+use leptos::{
+    oco::Oco,
+    reactive::{signal::ArcRwSignal, traits::Set, wrappers::read::ArcSignal},
+    task::spawn,
+};
+
 #[cfg(feature = "ssr")]
 mod ssr {
+    pub use crate::observer::{Release, StallEvent, SyncObserver, WaitEvent, WaitKind};
     pub use leptos::context::use_context;
-    pub use std::sync::{Arc, Mutex, RwLock};
-    pub use tokio::sync::watch::{channel, Receiver, Sender};
+    pub use std::{
+        collections::HashSet,
+        panic::Location,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc, Mutex, RwLock,
+        },
+        time::{Duration, Instant},
+    };
+    pub use tokio::sync::{
+        oneshot,
+        watch::{channel, Receiver, Sender},
+    };
 }
 
 #[cfg(feature = "ssr")]
@@ -11,6 +35,23 @@ use ssr::*;
 #[derive(Clone)]
 struct Phantom;
 
+/// The readiness state of a [`Ready`] or [`CoReady`], as reported by
+/// the various observable forms of readiness provided by [`ReadyHandle`]
+/// and `CoReady`.
+///
+/// Under CSR, the state is always `Ready` as no waiting will happen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReadyState {
+    /// The subscriptions will wait.
+    Pending,
+    /// The subscriptions will no longer wait.
+    Ready,
+}
+
+/// A cloneable future that resolves once the readiness state becomes
+/// [`ReadyState::Ready`].
+pub type ReadyFuture = Shared<BoxFuture<'static, ()>>;
+
 /// Encapsulates the underlying ready state that may be provided as a
 /// context by the [`SyncSsr`](crate::component::SyncSsr) component.
 ///
@@ -25,6 +66,8 @@ struct Phantom;
 pub struct Ready {
     #[cfg(feature = "ssr")]
     pub(crate) inner: Arc<ReadyInner>,
+    #[cfg(feature = "ssr")]
+    hold: Arc<Mutex<ReadyHold>>,
     _phantom: Phantom,
 }
 
@@ -44,9 +87,24 @@ pub struct Ready {
 pub struct CoReadyCoordinator {
     #[cfg(feature = "ssr")]
     inner: Arc<Mutex<Vec<CoReady>>>,
+    #[cfg(feature = "ssr")]
+    notified: Arc<AtomicBool>,
+    #[cfg(feature = "ssr")]
+    declared: Arc<Mutex<Option<HashSet<Oco<'static, str>>>>>,
+    #[cfg(feature = "ssr")]
+    sealed: Arc<AtomicBool>,
+    #[cfg(feature = "ssr")]
+    gate: Gate,
+    #[cfg(feature = "ssr")]
+    observer: Option<Arc<dyn SyncObserver>>,
     _phantom: Phantom,
 }
 
+// The asynchronous setup that every `CoReadySubscription` of the scope
+// must wait on before it may be released.
+#[cfg(feature = "ssr")]
+type Gate = Arc<RwLock<Option<ReadyFuture>>>;
+
 /// Encapsulates a coordinated ready state.
 ///
 /// Under SSR, this contains a `Sender` that may be cloned, and that all
@@ -63,9 +121,33 @@ pub struct CoReadyCoordinator {
 pub struct CoReady {
     #[cfg(feature = "ssr")]
     inner: Arc<ReadyInner>,
+    // Shared with the `CoReadyCoordinator` this was registered to.
+    #[cfg(feature = "ssr")]
+    sealed: Arc<AtomicBool>,
+    #[cfg(feature = "ssr")]
+    gate: Gate,
+    _phantom: Phantom,
+}
+
+/// A handle to a possibly available [`CoReadyCoordinator`].
+///
+/// Please refer to [`CoReadyCoordinator::handle`] for details as that's
+/// the only public associated function that will return this type.
+#[derive(Clone)]
+pub struct CoReadyCoordinatorHandle {
+    #[cfg(feature = "ssr")]
+    inner: Option<CoReadyCoordinator>,
     _phantom: Phantom,
 }
 
+/// The error returned when a writer is acquired from a [`CoReady`] whose
+/// [`CoReadyCoordinator`] was already [sealed](
+/// CoReadyCoordinatorHandle::seal).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SealedError {
+    name: Option<Oco<'static, str>>,
+}
+
 #[cfg(feature = "ssr")]
 #[derive(Clone)]
 pub(crate) struct ReadyInner {
@@ -76,6 +158,10 @@ pub(crate) struct ReadyInner {
     // `CoReadySubscriber` waiting after being notified of the first ready
     // state.
     manual_complete_armed: Arc<RwLock<bool>>,
+    name: Option<Oco<'static, str>>,
+    observer: Option<Arc<dyn SyncObserver>>,
+    // Ensures the release is only reported once to the observer.
+    released: Arc<AtomicBool>,
 }
 
 #[cfg(feature = "ssr")]
@@ -84,6 +170,34 @@ pub(crate) struct ReadySender {
     inner: ReadyInner,
 }
 
+/// Keeps its [`Ready`] from completing for as long as this is alive.
+///
+/// Acquired through [`Ready::hold`] or [`ReadyHandle::hold`], refer to
+/// the former for details.
+#[must_use = "the `Ready` is no longer held as soon as this is dropped"]
+pub struct ReadyGuard {
+    #[cfg(feature = "ssr")]
+    inner: Option<ReadyGuardInner>,
+    _phantom: Phantom,
+}
+
+#[cfg(feature = "ssr")]
+struct ReadyGuardInner {
+    ready: Ready,
+    // Dropped along with the guard, which stops the stall diagnostic.
+    _stall: Option<oneshot::Sender<()>>,
+}
+
+// The outstanding guards, and whether the `SyncSsr` has exited such that
+// the `Ready` is to be completed once the last guard is dropped.
+#[cfg(feature = "ssr")]
+#[derive(Default)]
+struct ReadyHold {
+    guards: usize,
+    exited: bool,
+    stall_deadline: Option<Duration>,
+}
+
 /// A handle to a possibly available [`Ready`] state.
 ///
 /// Please refer to [`Ready::handle`] for details as that's the only
@@ -150,6 +264,82 @@ impl Ready {
             _phantom: Phantom,
         }
     }
+
+    /// Acquire a handle to a possibly available instance of `Ready`
+    /// that was provided by a [`SyncSsr`](crate::component::SyncSsr)
+    /// component with the matching `name`.
+    ///
+    /// This is much like [`Ready::handle`], except rather than the
+    /// nearest `Ready`, the nearest one with the matching name will be
+    /// used, such that a `SyncSsr` further up the view tree may be
+    /// waited on.  Likewise, if none with the matching name is found,
+    /// no waiting will happen.
+    pub fn handle_named(name: impl Into<Oco<'static, str>>) -> ReadyHandle {
+        let name = name.into();
+        #[cfg(not(feature = "ssr"))]
+        let _ = name;
+        ReadyHandle {
+            #[cfg(feature = "ssr")]
+            inner: use_context::<ReadyScopes>().and_then(|scopes| {
+                scopes
+                    .0
+                    .iter()
+                    .rev()
+                    .find(|ready| ready.inner.name.as_ref() == Some(&name))
+                    .cloned()
+            }),
+            _phantom: Phantom,
+        }
+    }
+
+    /// Hold the [`Ready`] provided by the nearest [`SyncSsr`](
+    /// crate::component::SyncSsr) open until the returned [`ReadyGuard`]
+    /// is dropped.
+    ///
+    /// The `Ready` is normally completed as soon as everything enclosed
+    /// by `SyncSsr` has been rendered, which does not cover work started
+    /// through [`spawn`](leptos::task::spawn) or inside a future that is
+    /// not part of a `Suspense`, even if that work may set the signal a
+    /// reader is waiting on.  While any guard is alive, the `Ready` will
+    /// not complete even after `SyncSsr` is done rendering, so the guard
+    /// should be moved into such work and be dropped once it is done.
+    ///
+    /// Should the `stall_deadline` be provided to the `SyncSsr`, a guard
+    /// that is held past that deadline is reported as an error along
+    /// with the location where it was acquired, and to the [`SyncObserver`](
+    /// crate::observer::SyncObserver) as a [`StallEvent`](
+    /// crate::observer::StallEvent).  The guard continues to hold the
+    /// `Ready` regardless.
+    ///
+    /// Much like [`Ready::handle`], this make use of `use_context`, so a
+    /// guard that holds nothing is returned if no `Ready` is provided,
+    /// and also under CSR.  A guard acquired after the `Ready` completed
+    /// has no effect.
+    #[track_caller]
+    pub fn hold() -> ReadyGuard {
+        Self::handle().hold()
+    }
+}
+
+/// The named `Ready` that are available to the current reactive owner,
+/// ordered from the outermost to the innermost.
+#[cfg(feature = "ssr")]
+#[derive(Clone, Default)]
+pub(crate) struct ReadyScopes(Arc<Vec<Ready>>);
+
+#[cfg(feature = "ssr")]
+impl ReadyScopes {
+    /// Returns the scopes from the context extended with the provided
+    /// `Ready` if it has a name.
+    pub(crate) fn extend(ready: &Ready) -> Self {
+        let scopes = use_context::<ReadyScopes>().unwrap_or_default();
+        if ready.inner.name.is_none() {
+            return scopes;
+        }
+        let mut inner = scopes.0.as_ref().clone();
+        inner.push(ready.clone());
+        Self(Arc::new(inner))
+    }
 }
 
 #[cfg(feature = "ssr")]
@@ -164,15 +354,93 @@ impl CoReadyCoordinator {
     /// [`CoReadyCoordinator::notify`] is also called when all its
     /// children are done processing, to ensure that those subscription
     /// without senders can stop waiting.
-    pub(crate) fn new() -> Self {
+    ///
+    /// The coordinator and all `CoReady` registered to it will report
+    /// to the provided observer, falling back to the [`SyncObserver`]
+    /// that may be provided as a context if `None`.
+    pub(crate) fn new(observer: Option<Arc<dyn SyncObserver>>) -> Self {
         Self {
             inner: Arc::new(Mutex::new(Vec::new())),
+            notified: Arc::new(AtomicBool::new(false)),
+            declared: Arc::new(Mutex::new(None)),
+            sealed: Arc::new(AtomicBool::new(false)),
+            gate: Default::default(),
+            observer: observer.or_else(use_context::<Arc<dyn SyncObserver>>),
             _phantom: Phantom,
         }
     }
 
+    #[track_caller]
+    pub(crate) fn expect() -> Self {
+        let location = std::panic::Location::caller();
+        // FIXME a better error message
+        use_context::<CoReadyCoordinator>().unwrap_or_else(|| {
+            panic!("{location:?} expected a context of `CoReadyCoordinator` to be present")
+        })
+    }
+
+    // Every `CoReady` registered to this will wait on the future before
+    // their subscriptions may be released.
+    pub(crate) fn set_gate(&self, gate: ReadyFuture) {
+        *self.gate.write().expect("not poisoned") = Some(gate);
+    }
+
     fn register(&self, r: CoReady) {
-        self.inner.lock().expect("mutex not panicked").push(r);
+        let mut inner = self.inner.lock().expect("mutex not panicked");
+        // A `CoReady` registered after the notification, e.g. one created
+        // lazily, would otherwise never be notified.
+        if self.notified.load(Ordering::Acquire) {
+            Self::notify_one(&r, Release::Notify);
+        } else if Self::undeclared(&self.declared.lock().expect("mutex not panicked"), &r) {
+            Self::notify_one(&r, Release::Undeclared);
+        }
+        inner.push(r);
+    }
+
+    /// Declare the names of the [`CoReady`] that may still be written
+    /// to, typically by the route that was matched, such that every
+    /// other named `CoReady` will be notified right away rather than
+    /// when all the children of the `<SyncSsrSignal/>` are done.
+    ///
+    /// The `CoReady` that are registered after this is invoked will
+    /// also be notified immediately if they are not declared, while the
+    /// unnamed ones are never notified early.  Invoking this more than
+    /// once will add to the names that were declared.
+    ///
+    /// Much like the notification done once the `SyncSsrSignal` is done
+    /// rendering, a `CoReady` notified early will still wait on the
+    /// outstanding `ReadySender`s acquired from it, however a writer
+    /// acquired after its subscribers were released will not be waited
+    /// on.  Hence the declaration should cover every writer that may be
+    /// rendered under the route, including those under the nested routes.
+    ///
+    /// Under CSR this is a no-op.
+    pub fn declare_writers<I>(&self, names: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<Oco<'static, str>>,
+    {
+        let inner = self.inner.lock().expect("mutex not panicked");
+        let mut declared = self.declared.lock().expect("mutex not panicked");
+        declared
+            .get_or_insert_with(HashSet::new)
+            .extend(names.into_iter().map(Into::into));
+        if self.notified.load(Ordering::Acquire) {
+            return;
+        }
+        for ready in inner.iter() {
+            if Self::undeclared(&declared, ready) {
+                Self::notify_one(ready, Release::Undeclared);
+            }
+        }
+    }
+
+    // Only the named `CoReady` may be undeclared once declarations exist.
+    fn undeclared(declared: &Option<HashSet<Oco<'static, str>>>, ready: &CoReady) -> bool {
+        match (declared, &ready.inner.name) {
+            (Some(declared), Some(name)) => !declared.contains(name),
+            _ => false,
+        }
     }
 
     /// Notifies all `CoReady` states that they are primed, if they are
@@ -183,14 +451,46 @@ impl CoReadyCoordinator {
     /// If there are no outstanding `ReadySender`s then they should stop
     /// waiting, otherwise they should continue to wait.
     pub(crate) fn notify(&self) {
-        for ready in self.inner.lock().expect("mutex not panicked").iter() {
-            if *ready.inner.sender.borrow() != Some(true) {
-                let _ = ready.inner.sender.send(Some(false));
+        let inner = self.inner.lock().expect("mutex not panicked");
+        self.notified.store(true, Ordering::Release);
+        for ready in inner.iter() {
+            Self::notify_one(ready, Release::Notify);
+        }
+    }
+
+    // Like `notify`, but also marks every registered `CoReady` as sealed.
+    pub(crate) fn seal(&self) {
+        let inner = self.inner.lock().expect("mutex not panicked");
+        self.sealed.store(true, Ordering::Release);
+        self.notified.store(true, Ordering::Release);
+        for ready in inner.iter() {
+            Self::notify_one(ready, Release::Sealed);
+        }
+    }
+
+    fn notify_one(ready: &CoReady, release: Release) {
+        if *ready.inner.sender.borrow() != Some(true) {
+            ready.inner.sender.send_replace(Some(false));
+            if ready.inner.co_released(Some(false)) {
+                ready.inner.released(release);
             }
         }
     }
 }
 
+#[cfg(not(feature = "ssr"))]
+impl CoReadyCoordinator {
+    /// Declare the names of the [`CoReady`] that may still be written
+    /// to; under CSR this is a no-op.
+    pub fn declare_writers<I>(&self, names: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<Oco<'static, str>>,
+    {
+        let _ = names;
+    }
+}
+
 // should this be exposed
 // #[cfg(not(feature = "ssr"))]
 // impl CoReadyCoordinator {
@@ -261,16 +561,33 @@ impl CoReady {
     /// the current reactive owner or its ancestors.
     #[track_caller]
     pub fn new_with_options(manual_complete: bool) -> Self {
-        let location = std::panic::Location::caller();
-        // FIXME a better error message
-        let coordinator = use_context::<CoReadyCoordinator>().unwrap_or_else(|| {
-            panic!("{location:?} expected a context of `CoReadyCoordinator` to be present")
-        });
+        Self::new_inner(manual_complete, None)
+    }
+
+    // The name is only used for reporting to the observer.
+    #[track_caller]
+    pub(crate) fn new_inner(manual_complete: bool, name: Option<Oco<'static, str>>) -> Self {
+        Self::new_with_coordinator(&CoReadyCoordinator::expect(), manual_complete, name)
+    }
+
+    // For the types that retain the coordinator to register further
+    // `CoReady` regardless of the current reactive owner.
+    pub(crate) fn new_with_coordinator(
+        coordinator: &CoReadyCoordinator,
+        manual_complete: bool,
+        name: Option<Oco<'static, str>>,
+    ) -> Self {
         let (sender, _) = channel(None);
+        let observer = coordinator.observer.clone();
         let result = Self {
-            inner: Arc::new(ReadyInner::new(sender, manual_complete)),
+            inner: Arc::new(ReadyInner::new(sender, manual_complete, name, observer)),
+            sealed: coordinator.sealed.clone(),
+            gate: coordinator.gate.clone(),
             _phantom: Phantom,
         };
+        if let Some(observer) = &result.inner.observer {
+            observer.co_ready_created();
+        }
         coordinator.register(result.clone());
         result
     }
@@ -290,8 +607,36 @@ impl CoReady {
         }
     }
 
-    pub(crate) fn to_ready_sender(&self) -> ReadySender {
-        self.inner.to_ready_sender()
+    // A writer acquired after sealing will not be waited on, so it is
+    // refused a `ReadySender`.
+    pub(crate) fn try_to_ready_sender(&self) -> Result<ReadySender, SealedError> {
+        if self.sealed.load(Ordering::Acquire) {
+            Err(SealedError {
+                name: self.inner.name.clone(),
+            })
+        } else {
+            Ok(self.inner.to_ready_sender())
+        }
+    }
+
+    // For the writers that cannot fail, the refusal is reported instead.
+    pub(crate) fn to_ready_sender(&self) -> Option<ReadySender> {
+        self.try_to_ready_sender()
+            .inspect_err(|e| {
+                leptos::logging::error!("{e}");
+                if let Some(observer) = &self.inner.observer {
+                    observer.late_writer(self.inner.name.clone());
+                }
+            })
+            .ok()
+    }
+
+    fn gate_passed(&self) -> bool {
+        self.gate
+            .read()
+            .expect("not poisoned")
+            .as_ref()
+            .map_or(true, |gate| gate.peek().is_some())
     }
 }
 
@@ -312,6 +657,175 @@ impl Default for CoReady {
     }
 }
 
+impl CoReady {
+    /// Returns the current readiness state of this `CoReady`, i.e.
+    /// whether a [`CoReadySubscription`] would still wait.
+    pub fn state(&self) -> ReadyState {
+        #[cfg(feature = "ssr")]
+        let result = if self.gate_passed() && self.inner.co_released(*self.inner.sender.borrow()) {
+            ReadyState::Ready
+        } else {
+            ReadyState::Pending
+        };
+        #[cfg(not(feature = "ssr"))]
+        let result = ReadyState::Ready;
+        result
+    }
+
+    /// Returns a signal that tracks the readiness state of this
+    /// `CoReady`.
+    ///
+    /// Under SSR, a task will be spawned to wait for the readiness if it
+    /// is still pending, so an executor is required.
+    pub fn signal(&self) -> ArcSignal<ReadyState> {
+        readiness_signal(self.state(), self.subscribe().wait())
+    }
+
+    /// Returns a cloneable future that resolves once this `CoReady` is
+    /// ready.
+    pub fn shared(&self) -> ReadyFuture {
+        self.subscribe().wait().boxed().shared()
+    }
+
+    /// Returns a stream of the transitions of the readiness state of
+    /// this `CoReady`, starting with the current state.
+    pub fn stream(&self) -> impl Stream<Item = ReadyState> + Send + 'static {
+        readiness_stream(self.state(), self.subscribe().wait())
+    }
+}
+
+fn readiness_signal(
+    state: ReadyState,
+    wait: impl Future<Output = ()> + Send + 'static,
+) -> ArcSignal<ReadyState> {
+    if state == ReadyState::Ready {
+        return ArcSignal::stored(ReadyState::Ready);
+    }
+    let signal = ArcRwSignal::new(state);
+    spawn({
+        let signal = signal.clone();
+        async move {
+            wait.await;
+            signal.set(ReadyState::Ready);
+        }
+    });
+    signal.into()
+}
+
+fn readiness_stream(
+    state: ReadyState,
+    wait: impl Future<Output = ()> + Send + 'static,
+) -> impl Stream<Item = ReadyState> + Send + 'static {
+    let pending = (state == ReadyState::Pending).then_some(ReadyState::Pending);
+    stream::iter(pending).chain(stream::once(wait.map(|_| ReadyState::Ready)))
+}
+
+impl CoReadyCoordinator {
+    /// Acquire a handle to a possibly available instance of
+    /// `CoReadyCoordinator`, as provided by the nearest
+    /// [`SyncSsrSignal`](crate::component::SyncSsrSignal).
+    ///
+    /// Much like [`Ready::handle`], this makes use of [`use_context`]
+    /// and so this should be called at the component's top level, and
+    /// the methods on the handle will do nothing should there be no
+    /// `CoReadyCoordinator` provided as a context.
+    pub fn handle() -> CoReadyCoordinatorHandle {
+        CoReadyCoordinatorHandle {
+            #[cfg(feature = "ssr")]
+            inner: use_context::<CoReadyCoordinator>(),
+            _phantom: Phantom,
+        }
+    }
+}
+
+impl CoReadyCoordinatorHandle {
+    /// Seal the [`CoReadyCoordinator`] this handle points to, declaring
+    /// that no further writers will be acquired under it.
+    ///
+    /// Every [`CoReady`] registered to the coordinator will be notified
+    /// right away rather than when all the children of the enclosing
+    /// `<SyncSsrSignal/>` are done, such that those without outstanding
+    /// writers will be released.  This is useful in layouts where it is
+    /// known that the writers are all done early.
+    ///
+    /// Any writer acquired after this point will not be waited on, so it
+    /// is rejected; the `try_` variants of the methods acquiring writers,
+    /// such as [`SsrSignalResource::try_write_only`](
+    /// crate::signal::SsrSignalResource::try_write_only), will return a
+    /// [`SealedError`], whereas methods such as
+    /// [`SsrSignalResource::write_only`](
+    /// crate::signal::SsrSignalResource::write_only) will report it
+    /// through logging and to the [`SyncObserver`](
+    /// crate::observer::SyncObserver), returning a writer that only sets
+    /// the value without releasing any readers.
+    ///
+    /// Under CSR this is a no-op.
+    pub fn seal(&self) {
+        #[cfg(feature = "ssr")]
+        if let Some(coordinator) = &self.inner {
+            coordinator.seal();
+        }
+    }
+
+    /// Returns whether the [`CoReadyCoordinator`] this handle points to
+    /// was [sealed](CoReadyCoordinatorHandle::seal).
+    ///
+    /// Under CSR, or if the handle does not point to a coordinator, this
+    /// always returns `false`.
+    pub fn is_sealed(&self) -> bool {
+        #[cfg(feature = "ssr")]
+        let result = self
+            .inner
+            .as_ref()
+            .is_some_and(|coordinator| coordinator.sealed.load(Ordering::Acquire));
+        #[cfg(not(feature = "ssr"))]
+        let result = false;
+        result
+    }
+
+    /// Declare the names of the [`CoReady`] that may still be written to,
+    /// as per [`CoReadyCoordinator::declare_writers`].
+    ///
+    /// Under CSR this is a no-op.
+    pub fn declare_writers<I>(&self, names: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<Oco<'static, str>>,
+    {
+        #[cfg(feature = "ssr")]
+        if let Some(coordinator) = &self.inner {
+            coordinator.declare_writers(names);
+        }
+        #[cfg(not(feature = "ssr"))]
+        let _ = names;
+    }
+}
+
+impl SealedError {
+    /// The name of the [`CoReady`] the writer was acquired from, if one
+    /// was assigned.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+impl std::fmt::Display for SealedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(
+                f,
+                "writer for {name:?} acquired after the `CoReadyCoordinator` was sealed"
+            ),
+            None => write!(
+                f,
+                "writer acquired after the `CoReadyCoordinator` was sealed"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SealedError {}
+
 impl ReadyHandle {
     /// Subscribe to the [`Ready`] state.
     ///
@@ -324,6 +838,54 @@ impl ReadyHandle {
             _phantom: Phantom,
         }
     }
+
+    /// Returns the current readiness state of the [`Ready`] this handle
+    /// points to, i.e. whether a [`ReadySubscription`] would still wait.
+    ///
+    /// If the handle does not point to a `Ready`, the state is always
+    /// [`ReadyState::Ready`].
+    pub fn state(&self) -> ReadyState {
+        #[cfg(feature = "ssr")]
+        let result = match &self.inner {
+            Some(ready) if *ready.inner.sender.borrow() != Some(true) => ReadyState::Pending,
+            _ => ReadyState::Ready,
+        };
+        #[cfg(not(feature = "ssr"))]
+        let result = ReadyState::Ready;
+        result
+    }
+
+    /// Returns a signal that tracks the readiness state of the [`Ready`]
+    /// this handle points to.
+    ///
+    /// Under SSR, a task will be spawned to wait for the readiness if it
+    /// is still pending, so an executor is required.
+    pub fn signal(&self) -> ArcSignal<ReadyState> {
+        readiness_signal(self.state(), self.subscribe().wait())
+    }
+
+    /// Returns a cloneable future that resolves once the [`Ready`] this
+    /// handle points to is ready.
+    pub fn shared(&self) -> ReadyFuture {
+        self.subscribe().wait().boxed().shared()
+    }
+
+    /// Returns a stream of the transitions of the readiness state of the
+    /// [`Ready`] this handle points to, starting with the current state.
+    pub fn stream(&self) -> impl Stream<Item = ReadyState> + Send + 'static {
+        readiness_stream(self.state(), self.subscribe().wait())
+    }
+
+    /// Hold the underlying [`Ready`] open until the returned
+    /// [`ReadyGuard`] is dropped, as per [`Ready::hold`].
+    #[track_caller]
+    pub fn hold(&self) -> ReadyGuard {
+        ReadyGuard {
+            #[cfg(feature = "ssr")]
+            inner: self.inner.as_ref().map(|ready| ready.hold_inner()),
+            _phantom: Phantom,
+        }
+    }
 }
 
 #[cfg(not(feature = "ssr"))]
@@ -380,10 +942,12 @@ impl CoReadySubscription {
 #[cfg(feature = "ssr")]
 impl ReadySubscriptionInner {
     pub(crate) async fn wait_inner(mut self) {
+        let start = Instant::now();
         self.receiver
             .wait_for(|v| *v == Some(true))
             .await
             .expect("internal error: sender not properly managed");
+        self.ready.inner.waited(WaitKind::Ready, start);
         // XXX a 0 duration sleep seems to be required to mitigate
         // an issue where Suspend doesn't wake up after the resource
         // runs this async method, and this path does not have an
@@ -433,31 +997,69 @@ impl ReadySubscriptionInner {
 #[cfg(feature = "ssr")]
 impl CoReadySubscriptionInner {
     pub(crate) async fn wait_inner(mut self) {
-        let sender = &self.ready.inner.sender;
-        let manual_complete = self.ready.inner.manual_complete;
+        let start = Instant::now();
+        let gate = self.ready.gate.read().expect("not poisoned").clone();
+        if let Some(gate) = gate {
+            gate.await;
+        }
+        let inner = &self.ready.inner;
         self.receiver
-            .wait_for(|v| {
-                let v = *v;
-                v == Some(true)
-                    || (!manual_complete && v == Some(false) && sender.sender_count() == 1)
-            })
+            .wait_for(|v| inner.co_released(*v))
             .await
             .expect("internal error: sender not properly managed");
+        self.ready.inner.waited(WaitKind::CoReady, start);
     }
 }
 
 #[cfg(feature = "ssr")]
 impl ReadyInner {
-    pub(crate) fn new(sender: Sender<Option<bool>>, manual_complete: bool) -> Self {
+    pub(crate) fn new(
+        sender: Sender<Option<bool>>,
+        manual_complete: bool,
+        name: Option<Oco<'static, str>>,
+        observer: Option<Arc<dyn SyncObserver>>,
+    ) -> Self {
         Self {
             sender,
             manual_complete,
             manual_complete_armed: Arc::new(RwLock::new(false)),
+            name,
+            observer,
+            released: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    // Whether a `CoReadySubscription` should stop waiting upon seeing the
+    // value, which is either on completion, or when notified while there
+    // are no outstanding senders and manual completion isn't required.
+    pub(crate) fn co_released(&self, v: Option<bool>) -> bool {
+        v == Some(true)
+            || (!self.manual_complete && v == Some(false) && self.sender.sender_count() == 1)
+    }
+
+    pub(crate) fn waited(&self, kind: WaitKind, start: Instant) {
+        if let Some(observer) = &self.observer {
+            observer.wait(WaitEvent {
+                kind,
+                name: self.name.clone(),
+                duration: start.elapsed(),
+            });
+        }
+    }
+
+    pub(crate) fn released(&self, release: Release) {
+        if let Some(observer) = &self.observer {
+            if !self.released.swap(true, Ordering::Relaxed) {
+                observer.co_ready_released(release);
+            }
         }
     }
 
     pub(crate) fn complete(&self) {
-        let _ = self.sender.send(Some(true));
+        // Using `send_replace` as `send` would not retain the value if no
+        // receivers are alive at this point, which may be the case if the
+        // subscriptions are only created later, e.g. inside a `Suspend`.
+        self.sender.send_replace(Some(true));
         // TODO if we were to provide a tracing feature...
         // if let Ok(_) = self.sender.send(Some(true)) {
         //     leptos::logging::log!(
@@ -483,10 +1085,16 @@ impl ReadyInner {
 
 #[cfg(feature = "ssr")]
 impl Ready {
-    pub(crate) fn new() -> Ready {
+    // The observer falls back to the one provided as a context if `None`.
+    pub(crate) fn new(
+        name: Option<Oco<'static, str>>,
+        observer: Option<Arc<dyn SyncObserver>>,
+    ) -> Ready {
         let (sender, _) = channel(Some(false));
+        let observer = observer.or_else(use_context::<Arc<dyn SyncObserver>>);
         Ready {
-            inner: ReadyInner::new(sender, false).into(),
+            inner: ReadyInner::new(sender, false, name, observer).into(),
+            hold: Default::default(),
             _phantom: Phantom,
         }
     }
@@ -495,6 +1103,59 @@ impl Ready {
         self.inner.complete();
     }
 
+    pub(crate) fn set_stall_deadline(&self, deadline: Option<Duration>) {
+        self.hold.lock().expect("mutex not panicked").stall_deadline = deadline;
+    }
+
+    // Completes now, or once the last outstanding `ReadyGuard` is dropped.
+    pub(crate) fn exit(&self) {
+        let idle = {
+            let mut hold = self.hold.lock().expect("mutex not panicked");
+            hold.exited = true;
+            hold.guards == 0
+        };
+        if idle {
+            self.complete();
+        }
+    }
+
+    #[track_caller]
+    fn hold_inner(&self) -> ReadyGuardInner {
+        let location = Location::caller();
+        let deadline = {
+            let mut hold = self.hold.lock().expect("mutex not panicked");
+            hold.guards += 1;
+            hold.stall_deadline
+        };
+        let stall = deadline.map(|deadline| {
+            let (sender, receiver) = oneshot::channel::<()>();
+            let name = self.inner.name.clone();
+            let observer = self.inner.observer.clone();
+            spawn(async move {
+                // The receiver only errors once the guard is dropped.
+                if tokio::time::timeout(deadline, receiver).await.is_ok() {
+                    return;
+                }
+                leptos::logging::error!(
+                    "ReadyGuard acquired at {location} for Ready {name:?} is still held after \
+                     {deadline:?}"
+                );
+                if let Some(observer) = observer {
+                    observer.ready_stalled(StallEvent {
+                        name,
+                        location,
+                        deadline,
+                    });
+                }
+            });
+            sender
+        });
+        ReadyGuardInner {
+            ready: self.clone(),
+            _stall: stall,
+        }
+    }
+
     pub(crate) fn subscribe_inner(&self) -> ReadySubscriptionInner {
         ReadySubscriptionInner {
             ready: self.clone(),
@@ -503,6 +1164,20 @@ impl Ready {
     }
 }
 
+#[cfg(feature = "ssr")]
+impl Drop for ReadyGuardInner {
+    fn drop(&mut self) {
+        let release = {
+            let mut hold = self.ready.hold.lock().expect("mutex not panicked");
+            hold.guards -= 1;
+            hold.exited && hold.guards == 0
+        };
+        if release {
+            self.ready.complete();
+        }
+    }
+}
+
 #[cfg(feature = "ssr")]
 impl Drop for ReadySender {
     fn drop(&mut self) {
@@ -521,6 +1196,7 @@ impl Drop for ReadySender {
 impl ReadySender {
     pub(crate) fn complete(&self) {
         self.inner.complete();
+        self.inner.released(Release::Write);
     }
 }
 
@@ -532,7 +1208,12 @@ mod debug {
     impl fmt::Debug for Ready {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             f.debug_struct("Ready")
+                .field("name", &self.inner.name)
                 .field("resolved", &*self.inner.sender.borrow())
+                .field(
+                    "guards",
+                    &self.hold.lock().expect("mutex not panicked").guards,
+                )
                 .field("senders", &self.inner.sender.sender_count())
                 .field("subscribers", &self.inner.sender.receiver_count())
                 .finish()
@@ -542,6 +1223,7 @@ mod debug {
     impl fmt::Debug for CoReady {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             f.debug_struct("CoReady")
+                .field("name", &self.inner.name)
                 .field("resolved", &*self.inner.sender.borrow())
                 .field("senders", &self.inner.sender.sender_count())
                 .field("subscribers", &self.inner.sender.receiver_count())
@@ -550,6 +1232,14 @@ mod debug {
         }
     }
 
+    impl fmt::Debug for CoReadyCoordinatorHandle {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("CoReadyCoordinatorHandle")
+                .field("sealed", &self.is_sealed())
+                .finish()
+        }
+    }
+
     impl fmt::Debug for ReadyHandle {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             f.debug_struct("ReadyHandle")
@@ -558,6 +1248,14 @@ mod debug {
         }
     }
 
+    impl fmt::Debug for ReadyGuard {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("ReadyGuard")
+                .field("ready", &self.inner.as_ref().map(|v| v.ready.clone()))
+                .finish()
+        }
+    }
+
     impl fmt::Debug for ReadySubscription {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             f.debug_struct("ReadySubscription")
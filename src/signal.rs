@@ -3,10 +3,15 @@ use std::{
     fmt::{Debug, Formatter, Result},
     future::Future,
     panic::Location,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
 
+use futures::{future::BoxFuture, FutureExt};
 use leptos::{
+    oco::Oco,
     prelude::Suspend,
     reactive::{
         signal::{
@@ -17,7 +22,10 @@ use leptos::{
             DefinedAt, Get, GetUntracked, IsDisposed, Notify, Set, UntrackableGuard, Update, Write,
         },
     },
-    server::ArcResource,
+    server::{
+        codee::{string::JsonSerdeCodec, Decoder, Encoder},
+        ArcResource, FromEncodedStr, IntoEncodedString,
+    },
     suspense::Suspense,
     view, IntoView,
 };
@@ -25,6 +33,47 @@ use serde::{de::DeserializeOwned, Serialize};
 
 #[cfg(feature = "ssr")]
 use crate::ready::{CoReady, ReadySender};
+use crate::SealedError;
+
+mod collector;
+mod map;
+mod server_only;
+mod stored;
+mod swap;
+mod transaction;
+
+pub use collector::{SsrCollector, SsrContributor};
+pub use map::SsrSignalMap;
+pub use server_only::SsrOnlySignalResource;
+pub use stored::StoredSsrSignalResource;
+pub use swap::{NavigationSwap, SwapHold};
+pub use transaction::{SsrTransaction, SsrTxWriteSignal};
+
+/// The codec used for the serialization of the values of the resources
+/// provided by this module for hydration.
+///
+/// This is implemented for all the codecs provided by [`codee`](
+/// leptos::server::codee) that may be used with an [`ArcResource`], with
+/// [`JsonSerdeCodec`] being the default.  Codecs that are gated behind
+/// features in Leptos (e.g. `RkyvCodec`, or `SerdeLite` which wraps
+/// another codec such as `SerdeLite<JsonSerdeCodec>`) may be enabled
+/// through the features of this crate of the same name, or by enabling
+/// them on Leptos directly.
+pub trait ResourceCodec<T>:
+    Encoder<T, Error: Debug, Encoded: IntoEncodedString>
+    + Decoder<T, Error: Debug, Encoded: FromEncodedStr<DecodingError: Debug>>
+    + Send
+    + Sync
+{
+}
+
+impl<T, Ser> ResourceCodec<T> for Ser where
+    Ser: Encoder<T, Error: Debug, Encoded: IntoEncodedString>
+        + Decoder<T, Error: Debug, Encoded: FromEncodedStr<DecodingError: Debug>>
+        + Send
+        + Sync
+{
+}
 
 /// Provides a signal-resource pairing that together works to provide an
 /// asynchronously waitable read signal (through the resource) under SSR.
@@ -45,16 +94,19 @@ use crate::ready::{CoReady, ReadySender};
 /// a context, which typically involves having the
 /// [`<SyncSsrSignal/>`](crate::component::SyncSsrSignal) component be one of
 /// the ancestors of the component in the view tree.
-
-#[derive(Clone)]
-pub struct SsrSignalResource<T> {
-    inner: Arc<SsrSignalResourceInner<T>>,
+///
+/// The value is serialized for hydration with the [`ResourceCodec`] `Ser`,
+/// which defaults to [`JsonSerdeCodec`] as per `ArcResource`.  Pairings
+/// with other codecs may be created with the `_with_codec` variants of the
+/// constructors.
+pub struct SsrSignalResource<T, Ser = JsonSerdeCodec> {
+    inner: Arc<SsrSignalResourceInner<T, Ser>>,
 }
 
-struct SsrSignalResourceInner<T> {
+struct SsrSignalResourceInner<T, Ser> {
     #[cfg(feature = "ssr")]
     ready: CoReady,
-    resource: ArcResource<T>,
+    resource: ArcResource<T, Ser>,
     signal_read: ArcReadSignal<T>,
     signal_write: ArcWriteSignal<T>,
 }
@@ -78,26 +130,30 @@ struct SsrWriteSignalNotifier<T> {
 }
 
 struct SsrWriteSignalInner<T> {
+    // `None` for the writers acquired after the coordinator was sealed.
     #[cfg(feature = "ssr")]
-    ready_sender: ReadySender,
+    ready_sender: Option<ReadySender>,
     signal_write: ArcWriteSignal<T>,
 }
 
-impl<T> SsrSignalResourceInner<T>
+impl<T, Ser> SsrSignalResourceInner<T, Ser>
 where
-    T: Clone + Send + Sync + PartialEq + Serialize + DeserializeOwned + 'static,
+    T: Clone + Send + Sync + PartialEq + 'static,
+    Ser: ResourceCodec<T>,
 {
     #[track_caller]
-    fn new(value: T, _manual_complete: bool) -> Self {
+    fn new(value: T, _manual_complete: bool, _name: Option<Oco<'static, str>>) -> Self {
         #[cfg(feature = "ssr")]
-        let ready = CoReady::new_with_options(_manual_complete);
+        let ready = CoReady::new_inner(_manual_complete, _name);
         let (signal_read, signal_write) = ArcRwSignal::new(value.clone()).split();
+        #[cfg(not(feature = "ssr"))]
+        let initial = value.clone();
 
         // FIXME using `try` variants to work around issues with panics caused
         // by access of reactive value that were disposed (despite being Arc
         // variants), see:
         // - https://github.com/leptos-rs/leptos/issues/3729
-        let resource = ArcResource::new(
+        let resource = ArcResource::new_with_options(
             {
                 let signal_read = signal_read.clone();
                 // move || signal_read.get()
@@ -123,8 +179,40 @@ where
                     }
                 }
             },
+            false,
         );
 
+        // On the client, the resource will be holding the final value that
+        // was produced on the server if it was hydrated, whereas the inner
+        // signal still holds the initial value as none of the server-side
+        // writes were ever run here.  Adopt that value without notifying,
+        // as nothing could have subscribed to the signal at this point.
+        // Should the resource only resolve later, the value is adopted once
+        // it does, unless the signal was written to in the meantime.
+        #[cfg(not(feature = "ssr"))]
+        {
+            use leptos::reactive::traits::{UpdateUntracked, WithUntracked};
+            match resource.try_get_untracked().flatten() {
+                Some(value) => {
+                    signal_write.try_update_untracked(|inner| *inner = value);
+                }
+                None => {
+                    let resource = resource.clone();
+                    let signal_read = signal_read.clone();
+                    let signal_write = signal_write.clone();
+                    leptos::task::spawn(async move {
+                        let value = resource.await;
+                        let adopt = signal_read
+                            .try_with_untracked(|inner| *inner == initial && *inner != value)
+                            .unwrap_or(false);
+                        if adopt {
+                            signal_write.try_set(value);
+                        }
+                    });
+                }
+            }
+        }
+
         Self {
             #[cfg(feature = "ssr")]
             ready,
@@ -157,7 +245,27 @@ where
     #[track_caller]
     pub fn new(value: T) -> Self {
         Self {
-            inner: SsrSignalResourceInner::new(value, false).into(),
+            inner: SsrSignalResourceInner::new(value, false, None).into(),
+        }
+    }
+
+    /// Creates a named signal-resource pairing with the value of type
+    /// `T`.
+    ///
+    /// This is the same as [`SsrSignalResource::new`], except the name
+    /// will be reported along with the events that may be observed by
+    /// a [`SyncObserver`](crate::observer::SyncObserver), such that the
+    /// waits may be attributed to this particular pairing.
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.  This may be
+    /// resolved by providing the context by nesting this inside the
+    /// [`<SyncSsrSignal/>`](crate::component::SyncSsrSignal) component.
+    #[track_caller]
+    pub fn new_named(name: impl Into<Oco<'static, str>>, value: T) -> Self {
+        Self {
+            inner: SsrSignalResourceInner::new(value, false, Some(name.into())).into(),
         }
     }
 
@@ -183,12 +291,63 @@ where
     #[track_caller]
     pub fn new_must_notify(value: T) -> Self {
         Self {
-            inner: SsrSignalResourceInner::new(value, true).into(),
+            inner: SsrSignalResourceInner::new(value, true, None).into(),
+        }
+    }
+}
+
+impl<T, Ser> SsrSignalResource<T, Ser>
+where
+    T: Clone + Send + Sync + PartialEq + 'static,
+    Ser: ResourceCodec<T>,
+{
+    /// Creates a signal-resource pairing with the value of type `T` that
+    /// will be serialized with the codec `Ser`.
+    ///
+    /// This is otherwise the same as [`SsrSignalResource::new`].
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.
+    #[track_caller]
+    pub fn new_with_codec(value: T) -> Self {
+        Self {
+            inner: SsrSignalResourceInner::new(value, false, None).into(),
+        }
+    }
+
+    /// Creates a named signal-resource pairing with the value of type
+    /// `T` that will be serialized with the codec `Ser`.
+    ///
+    /// This is otherwise the same as [`SsrSignalResource::new_named`].
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.
+    #[track_caller]
+    pub fn new_named_with_codec(name: impl Into<Oco<'static, str>>, value: T) -> Self {
+        Self {
+            inner: SsrSignalResourceInner::new(value, false, Some(name.into())).into(),
+        }
+    }
+
+    /// Creates a signal-resource pairing with the value of type `T` that
+    /// will be serialized with the codec `Ser`.
+    ///
+    /// This is otherwise the same as [`SsrSignalResource::new_must_notify`].
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.
+    #[track_caller]
+    pub fn new_must_notify_with_codec(value: T) -> Self {
+        Self {
+            inner: SsrSignalResourceInner::new(value, true, None).into(),
         }
     }
 }
 
-impl<T> SsrSignalResource<T> {
+impl<T, Ser> SsrSignalResource<T, Ser> {
     /// Acquire the underlying `ArcResource` side of the pair.
     ///
     /// *Under SSR*, the underlying resource will asynchronously wait
@@ -203,7 +362,7 @@ impl<T> SsrSignalResource<T> {
     ///
     /// *Under CSR* no waiting would happen and so the underlying
     /// resource should act like an indirect [`ArcReadSignal`].
-    pub fn read_only(&self) -> ArcResource<T> {
+    pub fn read_only(&self) -> ArcResource<T, Ser> {
         self.inner.resource.clone()
     }
 
@@ -430,17 +589,25 @@ impl<T> SsrSignalResource<T> {
     /// problem when hydration is involved.  When a given signal is set
     /// inside a resource, the result of that is hydrated and none of
     /// the code within the resource runs on the client - this includes
-    /// the code that sets the signal.  The effect is that unexpected
-    /// application behavior post-hydration vs CSR, as the underlying
+    /// the code that sets the signal.  To keep the application behaving
+    /// the same post-hydration as under CSR, the underlying
     /// [`inner_read_only`](SsrSignalResource::inner_read_only) signal
-    /// (backed by a `ArcRwSignal`) won't be written to upon hydration.
-    /// This typically requires an additional work around to ensure this
-    /// signal is written to with the expected value, and is something
-    /// that [`PortletCtx`](crate::portlet::PortletCtx) does to ensure
-    /// expected client-side behavior.  All of this is by design, refer
-    /// to discussion under the GitHub issue [leptos-rs/leptos#4044](
+    /// (backed by a `ArcRwSignal`) adopts the value that the paired
+    /// `ArcResource` was hydrated with, such that this write signal will
+    /// hold the value produced on the server without any writes on the
+    /// client.  Should the resource only resolve after this pairing was
+    /// created, the value is adopted once it does, unless the signal was
+    /// written to in the meantime.  Refer to discussion under the GitHub
+    /// issue [leptos-rs/leptos#4044](
     /// https://github.com/leptos-rs/leptos/issues/4044) for additional
-    /// details.
+    /// details on why the writes inside the resource are not re-run.
+    ///
+    /// Should the `CoReadyCoordinator` be [sealed](
+    /// crate::CoReadyCoordinatorHandle::seal) already, the readers will
+    /// not be waiting for this writer, so it is reported as an error and
+    /// the writer returned will only set the underlying signal; use
+    /// [`try_write_only`](SsrSignalResource::try_write_only) to have it
+    /// rejected instead.
     pub fn write_only(&self) -> SsrWriteSignal<T> {
         SsrWriteSignal {
             inner: Arc::new(SsrWriteSignalInner {
@@ -451,9 +618,32 @@ impl<T> SsrSignalResource<T> {
         }
     }
 
+    /// Acquire a `SsrWriteSignal` as per [`write_only`](
+    /// SsrSignalResource::write_only), unless the `CoReadyCoordinator`
+    /// was already [sealed](crate::CoReadyCoordinatorHandle::seal).
+    ///
+    /// Since the paired resource will no longer wait on the writers that
+    /// were acquired after sealing, `write_only` will only report those
+    /// as errors, whereas this will return the [`SealedError`] instead.
+    ///
+    /// Under CSR this never fails.
+    pub fn try_write_only(&self) -> std::result::Result<SsrWriteSignal<T>, SealedError> {
+        Ok(SsrWriteSignal {
+            inner: Arc::new(SsrWriteSignalInner {
+                signal_write: self.inner.signal_write.clone(),
+                #[cfg(feature = "ssr")]
+                ready_sender: Some(self.inner.ready.try_to_ready_sender()?),
+            }),
+        })
+    }
+
     /// Returns the inner `ArcReadSignal`.  This bypasses the
     /// asynchronous waiting mechanism ensured by the `ArcResource`.
     /// Typically this is used for diagnostic purposes.
+    ///
+    /// When hydrating on the client, the signal will be holding the value
+    /// that the `ArcResource` was hydrated with rather than the initial
+    /// value, such that both will be in agreement from the start.
     pub fn inner_read_only(&self) -> ArcReadSignal<T> {
         self.inner.signal_read.clone()
     }
@@ -481,6 +671,17 @@ impl<T> SsrSignalResource<T> {
     /// provided by the `Set` trait will be invoked with the return
     /// value, effecting the changes.
     ///
+    /// The value produced under SSR is serialized along with the page,
+    /// such that when hydrating, the first run will not have the
+    /// `Future` repeat the fetch done on the server, and as the value
+    /// of the underlying signal is hydrated with the value the server
+    /// ended up with (see [`write_only`](SsrSignalResource::write_only)),
+    /// that run will not set it again.  Note that the `fetcher` is still
+    /// invoked for that run but the `Future` will be dropped without
+    /// being polled, so any reactive data that should trigger subsequent
+    /// fetches must be tracked outside of the `Future`, as shown in the
+    /// example below.
+    ///
     /// Internally, the full functionality of `SsrSignalResource` is
     /// only used under SSR, as the usage of the underlying locks must
     /// be used with `ArcResource`, but given the idea is that this
@@ -541,66 +742,10 @@ impl<T> SsrSignalResource<T> {
     pub fn set_with<Fut>(&self, fetcher: impl Fn() -> Fut + Send + Sync + 'static) -> impl IntoView
     where
         T: Clone + Send + Sync + 'static,
+        Ser: ResourceCodec<T>,
         Fut: Future<Output = T> + Send + 'static,
     {
-        let this = self.clone();
-        // This fetcher will need to be called inside a resource first as it
-        // reconfigures the underlying `SsrSignalResource` to manual release
-        // mode upon acquisition of the `SsrWriteSignal` - this ensures the
-        // `ArcResource` on the other end will only unlock when signaled, which
-        // the following resource will as it directly leads to `.set()` being
-        // called to signal the unlock.
-        let fetcher = Arc::new(fetcher);
-        // Note this resource only used on the server - the fetcher is invoked
-        // again directly to write to underlying `ArcWriteSignal` directly, and
-        // this second invocation will not be problematic as the same data is
-        // being written to for the second time under SSR, and for the first
-        // (and only) time under hydrate/CSR which would set the underlying
-        // signal with the real expected value without the other end waiting.
-        #[allow(unused_variables)]
-        let res = ArcResource::new(|| (), {
-            let this = this.clone();
-            let fetcher = fetcher.clone();
-            move |_| {
-                let ws = this.write_only();
-                let fut = fetcher();
-                async move {
-                    ws.set(fut.await);
-                }
-            }
-        });
-        // Under SSR, the resource declared above must be used to ensure the
-        // write signal is set at the appropriate time after the unlock as
-        // per the usage of the provided `write_only` signal.
-        #[cfg(feature = "ssr")]
-        let result = view! {
-            <Suspense>{
-                move || {
-                    let res = res.clone();
-                    Suspend::new(async move {
-                        res.await;
-                    })
-                }
-            }</Suspense>
-        };
-        // Under not SSR (i.e. hydrate/CSR), the suspend can invoke the
-        // future directly to apply the result to the underlying
-        // `ArcWriteSignal`.
-        #[cfg(not(feature = "ssr"))]
-        let result = view! {
-            <Suspense>{
-                let this = this.clone();
-                let fetcher = fetcher.clone();
-                move || {
-                    let this = this.clone();
-                    let fut = fetcher();
-                    Suspend::new(async move {
-                        this.inner_write_only().set(fut.await);
-                    })
-                }
-            }</Suspense>
-        };
-        result
+        self.update_with(fetcher, |value, new_value| *value = new_value)
     }
 
     /// With the provided fetcher and update function, generate a view
@@ -621,6 +766,11 @@ impl<T> SsrSignalResource<T> {
     /// `Future` that was generated from the `fetcher`, which should
     /// effect the desired changes.
     ///
+    /// As with [`set_with`](SsrSignalResource::set_with), the first run
+    /// when hydrating will neither repeat the fetch made on the server
+    /// nor apply the update again on top of the hydrated value, which
+    /// already includes it.
+    ///
     /// Internally, the full functionality of `SsrSignalResource` is
     /// only used under SSR, as the usage of the underlying locks must
     /// be used with `ArcResource`, but given the idea is that this
@@ -684,81 +834,269 @@ impl<T> SsrSignalResource<T> {
     ) -> impl IntoView
     where
         T: Clone + Send + Sync + 'static,
+        Ser: ResourceCodec<T>,
+        Fut: Future<Output = U> + Send + 'static,
+        U: Send + 'static,
+    {
+        let run = self.update_runs(fetcher, updater);
+        view! {
+            <Suspense>{move || Suspend::new(run())}</Suspense>
+        }
+    }
+
+    // Produces the future for every run of the suspend returned by
+    // `update_with`, which is kept separate from the view such that the
+    // runs may be driven directly.
+    pub(crate) fn update_runs<Fut, U>(
+        &self,
+        fetcher: impl Fn() -> Fut + Send + Sync + 'static,
+        updater: impl Fn(&mut T, U) + Send + Sync + 'static,
+    ) -> impl Fn() -> BoxFuture<'static, ()> + Send + Sync + 'static
+    where
+        T: Clone + Send + Sync + 'static,
+        Ser: ResourceCodec<T>,
         Fut: Future<Output = U> + Send + 'static,
+        U: Send + 'static,
     {
         let this = self.clone();
         // This fetcher will need to be called inside a resource first as it
         // reconfigures the underlying `SsrSignalResource` to manual release
         // mode upon acquisition of the `SsrWriteSignal` - this ensures the
         // `ArcResource` on the other end will only unlock when signaled, which
-        // the following resource will as it directly leads to `.set()` being
-        // called to signal the unlock.
+        // the following resource will as it directly leads to `.update()`
+        // being called to signal the unlock.
         let fetcher = Arc::new(fetcher);
         let updater = Arc::new(updater);
-        // Note this resource only used on the server - the fetcher is invoked
-        // again directly to write to underlying `ArcWriteSignal` directly, and
-        // this second invocation will not be problematic as the same data is
-        // being written to for the second time under SSR, and for the first
-        // (and only) time under hydrate/CSR which would set the underlying
-        // signal with the real expected value without the other end waiting.
-        #[allow(unused_variables)]
+        // Set as the resource runs, which happens as it is created unless it
+        // was hydrated.
+        #[cfg(not(feature = "ssr"))]
+        let polled = Arc::new(AtomicBool::new(false));
+        // Under SSR, this resource applies the update, and its completion is
+        // serialized along with the page.  Under hydrate, this resource will
+        // be hydrated with that instead of running, which allows the first
+        // run below to detect that the update was already made on the server
+        // and carried over by the value the underlying signal has adopted.
         let res = ArcResource::new(|| (), {
+            #[cfg(feature = "ssr")]
             let this = this.clone();
+            #[cfg(feature = "ssr")]
             let fetcher = fetcher.clone();
+            #[cfg(feature = "ssr")]
             let updater = updater.clone();
+            #[cfg(not(feature = "ssr"))]
+            let polled = polled.clone();
             move |_| {
+                #[cfg(feature = "ssr")]
                 let ws = this.write_only();
+                #[cfg(feature = "ssr")]
                 let fut = fetcher();
+                #[cfg(feature = "ssr")]
                 let updater = updater.clone();
+                #[cfg(not(feature = "ssr"))]
+                let polled = polled.clone();
                 async move {
-                    let value = fut.await;
-                    ws.update(|v| {
-                        updater(v, value);
-                    });
+                    #[cfg(not(feature = "ssr"))]
+                    polled.store(true, Ordering::Relaxed);
+                    #[cfg(feature = "ssr")]
+                    {
+                        let value = fut.await;
+                        ws.update(|v| {
+                            updater(v, value);
+                        });
+                    }
                 }
             }
         });
+
         // Under SSR, the resource declared above must be used to ensure the
         // write signal is set at the appropriate time after the unlock as
         // per the usage of the provided `write_only` signal.
         #[cfg(feature = "ssr")]
-        let result = view! {
-            <Suspense>{
-                move || {
-                    let res = res.clone();
-                    Suspend::new(async move {
-                        res.await;
-                    })
-                }
-            }</Suspense>
+        let result = move || {
+            let res = res.clone();
+            async move {
+                res.await;
+            }
+            .boxed()
         };
-        // Under not SSR (i.e. hydrate/CSR), the suspend can invoke the
-        // future directly to apply the result to the underlying
-        // `ArcWriteSignal`.
+
+        // Under not SSR (i.e. hydrate/CSR), should the resource be hydrated,
+        // the underlying signal would have adopted the value the server ended
+        // up with, which already has this update applied, so the first run of
+        // the suspend will not apply it again.  The fetcher is still invoked
+        // so that any reactive values it tracks will trigger the subsequent
+        // runs, but the returned future is dropped without being polled.  All
+        // other runs will await the future directly to apply the result to
+        // the underlying `ArcWriteSignal`.  Should a `NavigationSwap` be
+        // provided, every run holds it until the update is staged or the
+        // suspend is dropped.
         #[cfg(not(feature = "ssr"))]
-        let result = view! {
-            <Suspense>{
+        let result = {
+            let _ = res;
+            let hydrated = !polled.load(Ordering::Relaxed);
+            let first_run = Arc::new(AtomicBool::new(true));
+            let swap = leptos::prelude::use_context::<NavigationSwap>();
+            move || {
                 let this = this.clone();
-                let fetcher = fetcher.clone();
+                let swap = swap.clone();
+                let hold = swap.as_ref().map(NavigationSwap::hold);
+                let fut = fetcher();
                 let updater = updater.clone();
-                move || {
-                    let this = this.clone();
-                    let fut = fetcher();
-                    let updater = updater.clone();
-                    Suspend::new(async move {
-                        let value = fut.await;
-                        this.inner_write_only().update(|v| {
-                            updater(v, value);
-                        });
-                    })
+                let skip = first_run.swap(false, Ordering::Relaxed) && hydrated;
+                async move {
+                    let value = if skip { None } else { Some(fut.await) };
+                    if let Some(value) = value {
+                        let ws = this.inner_write_only();
+                        let update = move || {
+                            ws.update(|v| {
+                                updater(v, value);
+                            })
+                        };
+                        match swap {
+                            Some(swap) => swap.stage(update),
+                            None => update(),
+                        }
+                    }
+                    drop(hold);
                 }
-            }</Suspense>
+                .boxed()
+            }
         };
         result
     }
 }
 
-impl<T> Debug for SsrSignalResource<T> {
+/// Provides a one-shot resource for a value delivered by a single producer
+/// under synchronized SSR.
+///
+/// This is a lighter alternative to [`SsrSignalResource`] for when there
+/// is exactly one producer that delivers the value exactly once.  The
+/// producer acquires an [`SsrOnceSender`] through [`SsrOnceResource::sender`]
+/// following the same rules as [`SsrSignalResource::write_only`], i.e. it
+/// must be acquired inside a resource closure, or in its `async` block
+/// before any `.await` points.  Sending the value through the sender will
+/// release the readers of the [`read_only`](SsrOnceResource::read_only)
+/// resource with that value, while dropping the sender without sending
+/// will release them with the default value.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_sync_ssr::signal::SsrOnceResource;
+/// #
+/// #[component]
+/// fn Producer() -> impl IntoView {
+///     let once = expect_context::<SsrOnceResource<String>>();
+///     let res = ArcResource::new(
+///         || (),
+///         move |_| {
+///             let sender = once.sender();
+///             async move {
+///                 // the sender is consumed, so it can only be sent once.
+///                 let _ = sender.send("Hello world!".to_string());
+///             }
+///         },
+///     );
+///     view! {
+///         <Suspense>{move || {
+///             let res = res.clone();
+///             Suspend::new(async move { res.await })
+///         }}</Suspense>
+///     }
+/// }
+/// ```
+///
+/// Note that this type can only be created inside components that have have
+/// the [`CoReadyCoordinator`](crate::ready::CoReadyCoordinator) be provided as
+/// a context, as per [`SsrSignalResource`].
+#[derive(Clone)]
+pub struct SsrOnceResource<T> {
+    inner: SsrSignalResource<T>,
+    sent: Arc<AtomicBool>,
+}
+
+/// The one-shot sender created by [`SsrOnceResource::sender`].
+///
+/// Sending consumes the sender, and dropping it without sending will
+/// release the readers with the default value.
+// Not Clone for the same reason as `SsrWriteSignal`.
+pub struct SsrOnceSender<T> {
+    inner: SsrWriteSignal<T>,
+    sent: Arc<AtomicBool>,
+}
+
+impl<T> SsrOnceResource<T>
+where
+    T: Clone + Send + Sync + PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    /// Creates a one-shot resource, with the provided default value for
+    /// when no value is sent.
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.  This may be
+    /// resolved by providing the context by nesting this inside the
+    /// [`<SyncSsrSignal/>`](crate::component::SyncSsrSignal) component.
+    #[track_caller]
+    pub fn new(default: T) -> Self {
+        Self {
+            inner: SsrSignalResource::new(default),
+            sent: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+impl<T> SsrOnceResource<T> {
+    /// Acquire the underlying `ArcResource` that will provide the sent
+    /// value, or the default value should the sender be dropped or be
+    /// never acquired, as per [`SsrSignalResource::read_only`].
+    pub fn read_only(&self) -> ArcResource<T> {
+        self.inner.read_only()
+    }
+
+    /// Acquire the sender for the value.
+    ///
+    /// Refer to [`SsrSignalResource::write_only`] for where this should
+    /// be acquired to ensure the readers will wait for the value.
+    pub fn sender(&self) -> SsrOnceSender<T> {
+        SsrOnceSender {
+            inner: self.inner.write_only(),
+            sent: self.sent.clone(),
+        }
+    }
+}
+
+impl<T: 'static> SsrOnceSender<T> {
+    /// Send the value to the readers of the paired [`SsrOnceResource`].
+    ///
+    /// Returns the value back as an error if a value was already sent by
+    /// another sender acquired from the same `SsrOnceResource`.
+    pub fn send(self, value: T) -> std::result::Result<(), T> {
+        if self.sent.swap(true, Ordering::AcqRel) {
+            return Err(value);
+        }
+        self.inner.set(value);
+        Ok(())
+    }
+}
+
+impl<T> Debug for SsrOnceResource<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("SsrOnceResource")
+            .field("inner", &self.inner)
+            .field("sent", &self.sent.load(Ordering::Relaxed))
+            .finish()
+    }
+}
+
+impl<T, Ser> Clone for SsrSignalResource<T, Ser> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T, Ser> Debug for SsrSignalResource<T, Ser> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         f.debug_struct("SsrSignalResource")
             .field("read_only", &self.inner.resource)
@@ -810,7 +1148,9 @@ impl<T> Notify for SsrWriteSignal<T> {
         // assume when this is marked dirty, a change has happened and so it
         // is now safe for the reader to continue execution
         #[cfg(feature = "ssr")]
-        self.inner.ready_sender.complete();
+        if let Some(ready_sender) = &self.inner.ready_sender {
+            ready_sender.complete();
+        }
     }
 }
 
@@ -820,6 +1160,8 @@ impl<T> Notify for SsrWriteSignalNotifier<T> {
         // assume when this is marked dirty, a change has happened and so it
         // is now safe for the reader to continue execution
         #[cfg(feature = "ssr")]
-        self.inner.ready_sender.complete();
+        if let Some(ready_sender) = &self.inner.ready_sender {
+            ready_sender.complete();
+        }
     }
 }
@@ -0,0 +1,460 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Formatter, Result},
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+#[cfg(not(feature = "ssr"))]
+use std::sync::atomic::AtomicBool;
+#[cfg(feature = "ssr")]
+use std::sync::Mutex;
+
+use futures::{future::BoxFuture, FutureExt};
+use leptos::{
+    prelude::{on_cleanup, Suspend},
+    reactive::{
+        signal::{ArcReadSignal, ArcRwSignal, ArcWriteSignal},
+        traits::{Get, GetUntracked, Update},
+    },
+    server::{codee::string::JsonSerdeCodec, ArcResource},
+    suspense::Suspense,
+    view, IntoView,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::ResourceCodec;
+#[cfg(feature = "ssr")]
+use crate::ready::{CoReady, CoReadyCoordinator, ReadySender};
+
+type Contributions<T> = BTreeMap<usize, Vec<T>>;
+
+/// Provides a collection of items accumulated from any number of
+/// contributors, such as flash messages or page-level actions that are
+/// declared by components scattered throughout the view tree.
+///
+/// Every [`SsrContributor`] acquired through [`contributor`](
+/// SsrCollector::contributor) has its own lock, and *under SSR* the
+/// resource acquired through [`read_only`](SsrCollector::read_only)
+/// will only resolve once the enclosing [`<SyncSsrSignal/>`](
+/// crate::component::SyncSsrSignal) is done processing, and every
+/// contributor acquired by then has either [finished](
+/// SsrContributor::finish) or was dropped.
+///
+/// The items are returned ordered by their contributor, in the order
+/// the contributors were acquired, followed by the order the items were
+/// pushed.  As long as the contributors are acquired as the components
+/// are being created, like what [`contribute_with`](
+/// SsrCollector::contribute_with) does, this will be the order of the
+/// view tree, which will be the same both on the server and the client.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_sync_ssr::signal::SsrCollector;
+/// #
+/// #[component]
+/// fn Flash() -> impl IntoView {
+///     let messages = expect_context::<SsrCollector<String>>().read_only();
+///     view! {
+///         <Suspense>{move || {
+///             let messages = messages.clone();
+///             Suspend::new(async move {
+///                 messages.await
+///                     .into_iter()
+///                     .map(|message| view! { <p>{message}</p> })
+///                     .collect_view()
+///             })
+///         }}</Suspense>
+///     }
+/// }
+///
+/// #[component]
+/// fn Saved() -> impl IntoView {
+///     let collector = expect_context::<SsrCollector<String>>();
+///     view! {
+///         {collector.contribute_with(|| async { vec!["Saved.".to_string()] })}
+///     }
+/// }
+/// ```
+///
+/// Note that this type can only be created inside components that have have
+/// the [`CoReadyCoordinator`](crate::ready::CoReadyCoordinator) be provided as
+/// a context, as per [`SsrSignalResource`](super::SsrSignalResource).
+///
+/// The items are serialized for hydration with the [`ResourceCodec`]
+/// `Ser`, which defaults to [`JsonSerdeCodec`], with collectors using other
+/// codecs created through [`new_with_codec`](SsrCollector::new_with_codec).
+pub struct SsrCollector<T, Ser = JsonSerdeCodec> {
+    inner: Arc<SsrCollectorInner<T, Ser>>,
+}
+
+struct SsrCollectorInner<T, Ser> {
+    // Rather than the reactive owner, as the collector is typically provided
+    // as a context of that same owner.
+    #[cfg(feature = "ssr")]
+    coordinator: CoReadyCoordinator,
+    #[cfg(feature = "ssr")]
+    contributors: Arc<Mutex<Vec<CoReady>>>,
+    next_index: AtomicUsize,
+    resource: ArcResource<Vec<T>, Ser>,
+    signal_read: ArcReadSignal<Contributions<T>>,
+    signal_write: ArcWriteSignal<Contributions<T>>,
+}
+
+/// A contributor to a [`SsrCollector`], created by
+/// [`SsrCollector::contributor`].
+///
+/// *Under SSR*, the readers of the collector will wait until this is
+/// [finished](SsrContributor::finish) or dropped, in the same manner
+/// as a [`SsrWriteSignal`](super::SsrWriteSignal) that was acquired
+/// before the `CoReadyCoordinator` notifies.  Unlike that, pushing an
+/// item does not release the lock, as a contributor may push any
+/// number of items.
+///
+/// The items provided by this contributor remain part of the collection
+/// after it is finished or dropped, until the reactive owner it was
+/// acquired under is cleaned up, such as when the component that
+/// acquired it is unmounted under CSR.
+// Not Clone for the same reason as `SsrWriteSignal`.
+pub struct SsrContributor<T> {
+    index: usize,
+    signal_write: ArcWriteSignal<Contributions<T>>,
+    // `None` for the contributors acquired after the coordinator was sealed.
+    #[cfg(feature = "ssr")]
+    ready_sender: Option<ReadySender>,
+}
+
+impl<T, Ser> Clone for SsrCollector<T, Ser> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> SsrCollector<T>
+where
+    T: Clone + Send + Sync + PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    /// Creates an empty collector.
+    ///
+    /// The `CoReadyCoordinator` is retained, such that the lock for
+    /// every contributor will be registered to it regardless of where
+    /// the contributor was acquired.
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.  This may be
+    /// resolved by providing the context by nesting this inside the
+    /// [`<SyncSsrSignal/>`](crate::component::SyncSsrSignal) component.
+    #[track_caller]
+    pub fn new() -> Self {
+        Self::new_with_codec()
+    }
+}
+
+impl<T, Ser> SsrCollector<T, Ser>
+where
+    T: Clone + Send + Sync + PartialEq + 'static,
+    Ser: ResourceCodec<Vec<T>> + 'static,
+{
+    /// Creates an empty collector, where the items will be serialized
+    /// with the codec `Ser`.
+    ///
+    /// This is otherwise the same as [`SsrCollector::new`].
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.
+    #[track_caller]
+    pub fn new_with_codec() -> Self {
+        // This is only ever released by the coordinator, so that every
+        // contributor acquired before then will be waited on.
+        #[cfg(feature = "ssr")]
+        let coordinator = CoReadyCoordinator::expect();
+        #[cfg(feature = "ssr")]
+        let ready = CoReady::new_with_coordinator(&coordinator, false, None);
+        #[cfg(feature = "ssr")]
+        let contributors = Arc::new(Mutex::new(Vec::<CoReady>::new()));
+        let (signal_read, signal_write) = ArcRwSignal::new(Contributions::<T>::new()).split();
+
+        let resource = ArcResource::<_, Ser>::new_with_options(
+            {
+                let signal_read = signal_read.clone();
+                move || signal_read.try_get().unwrap_or_default()
+            },
+            {
+                #[cfg(feature = "ssr")]
+                let contributors = contributors.clone();
+                let signal_read = signal_read.clone();
+                move |original| {
+                    #[cfg(feature = "ssr")]
+                    let subscriber = ready.subscribe();
+                    #[cfg(feature = "ssr")]
+                    let contributors = contributors.clone();
+                    let signal_read = signal_read.clone();
+                    async move {
+                        #[cfg(feature = "ssr")]
+                        {
+                            subscriber.wait().await;
+                            // Contributors may still be acquired while the
+                            // earlier ones are being waited on, so keep on
+                            // waiting until no new ones have appeared.
+                            let mut waited = 0;
+                            loop {
+                                let pending = contributors.lock().expect("mutex not panicked")
+                                    [waited..]
+                                    .iter()
+                                    .map(CoReady::subscribe)
+                                    .collect::<Vec<_>>();
+                                if pending.is_empty() {
+                                    break;
+                                }
+                                waited += pending.len();
+                                for subscriber in pending {
+                                    subscriber.wait().await;
+                                }
+                            }
+                        }
+                        signal_read
+                            .try_get_untracked()
+                            .unwrap_or(original)
+                            .into_values()
+                            .flatten()
+                            .collect()
+                    }
+                }
+            },
+            false,
+        );
+
+        Self {
+            inner: Arc::new(SsrCollectorInner {
+                #[cfg(feature = "ssr")]
+                coordinator,
+                #[cfg(feature = "ssr")]
+                contributors,
+                next_index: AtomicUsize::new(0),
+                resource,
+                signal_read,
+                signal_write,
+            }),
+        }
+    }
+
+    /// Acquire the underlying `ArcResource`, which resolves to all the
+    /// items collected so far, in order.
+    ///
+    /// *Under SSR*, the resource will wait as described for
+    /// [`SsrCollector`].  *Under CSR*, no waiting will happen, and the
+    /// resource will be refetched as contributors provide their items.
+    pub fn read_only(&self) -> ArcResource<Vec<T>, Ser> {
+        self.inner.resource.clone()
+    }
+
+    /// Acquire a new [`SsrContributor`], which will be ordered after all
+    /// the contributors acquired before it.
+    ///
+    /// Much like [`SsrSignalResource::write_only`](
+    /// super::SsrSignalResource::write_only), this should be acquired as
+    /// the component is being created, or inside a resource closure but
+    /// before any `.await` points, so that the readers under SSR will be
+    /// waiting for it.
+    ///
+    /// The items provided by the contributor are removed from the
+    /// collection as the current reactive owner is cleaned up.
+    pub fn contributor(&self) -> SsrContributor<T> {
+        let index = self.inner.next_index.fetch_add(1, Ordering::Relaxed);
+        on_cleanup({
+            let signal_write = self.inner.signal_write.clone();
+            move || {
+                signal_write.try_update(|contributions| contributions.remove(&index));
+            }
+        });
+        #[cfg(feature = "ssr")]
+        let ready_sender = {
+            let ready = CoReady::new_with_coordinator(&self.inner.coordinator, false, None);
+            let ready_sender = ready.to_ready_sender();
+            self.inner
+                .contributors
+                .lock()
+                .expect("mutex not panicked")
+                .push(ready);
+            ready_sender
+        };
+        SsrContributor {
+            index,
+            signal_write: self.inner.signal_write.clone(),
+            #[cfg(feature = "ssr")]
+            ready_sender,
+        }
+    }
+
+    /// With the provided fetcher, generate a view to be added to a view
+    /// tree that will contribute the items it produces to this collector.
+    ///
+    /// The contributor is acquired immediately, so the items will be in
+    /// the order of the view tree, and they will replace the previously
+    /// contributed items whenever the fetcher runs again.  Like
+    /// [`SsrSignalResource::set_with`](super::SsrSignalResource::set_with),
+    /// the items produced under SSR are serialized along with the page,
+    /// and the first run when hydrating will apply them rather than have
+    /// the `Future` repeat the fetch done on the server.
+    pub fn contribute_with<Fut>(
+        &self,
+        fetcher: impl Fn() -> Fut + Send + Sync + 'static,
+    ) -> impl IntoView
+    where
+        Fut: Future<Output = Vec<T>> + Send + 'static,
+    {
+        let run = self.contribute_runs(fetcher);
+        view! {
+            <Suspense>{move || Suspend::new(run())}</Suspense>
+        }
+    }
+
+    // Produces the future for every run of the suspend returned by
+    // `contribute_with`, which is kept separate from the view such that the
+    // runs may be driven directly.
+    pub(crate) fn contribute_runs<Fut>(
+        &self,
+        fetcher: impl Fn() -> Fut + Send + Sync + 'static,
+    ) -> impl Fn() -> BoxFuture<'static, ()> + Send + Sync + 'static
+    where
+        Fut: Future<Output = Vec<T>> + Send + 'static,
+    {
+        let contributor = Arc::new(self.contributor());
+        let fetcher = Arc::new(fetcher);
+        let res = ArcResource::<_, Ser>::new_with_options(
+            || (),
+            {
+                #[cfg(feature = "ssr")]
+                let contributor = contributor.clone();
+                let fetcher = fetcher.clone();
+                move |_| {
+                    #[cfg(feature = "ssr")]
+                    let contributor = contributor.clone();
+                    #[cfg(feature = "ssr")]
+                    let fut = fetcher();
+                    // Under not SSR, this resource only provides the items
+                    // for the first run, as the subsequent runs are made by
+                    // the suspend, so it must not be refetched as the values
+                    // tracked by the fetcher change.
+                    #[cfg(not(feature = "ssr"))]
+                    let fut = leptos::prelude::untrack(|| fetcher());
+                    async move {
+                        let items = fut.await;
+                        // Under not SSR, the items are applied by the suspend.
+                        #[cfg(feature = "ssr")]
+                        {
+                            contributor.set(items.clone());
+                            if let Some(ready_sender) = &contributor.ready_sender {
+                                ready_sender.complete();
+                            }
+                        }
+                        items
+                    }
+                }
+            },
+            false,
+        );
+        #[cfg(feature = "ssr")]
+        let result = {
+            let _ = contributor;
+            move || {
+                let res = res.clone();
+                async move {
+                    res.await;
+                }
+                .boxed()
+            }
+        };
+        // Same as `SsrSignalResource::update_with`, the first run applies the
+        // hydrated items, with subsequent runs using the future directly.
+        #[cfg(not(feature = "ssr"))]
+        let result = {
+            let first_run = Arc::new(AtomicBool::new(true));
+            move || {
+                let contributor = contributor.clone();
+                let fut = fetcher();
+                let res = first_run
+                    .swap(false, Ordering::Relaxed)
+                    .then(|| res.clone());
+                async move {
+                    let items = match res {
+                        Some(res) => res.await,
+                        None => fut.await,
+                    };
+                    contributor.set(items);
+                }
+                .boxed()
+            }
+        };
+        result
+    }
+
+    /// Returns the items collected so far, in order, bypassing the
+    /// asynchronous waiting mechanism ensured by the `ArcResource`.
+    pub fn get_untracked(&self) -> Vec<T> {
+        self.inner
+            .signal_read
+            .try_get_untracked()
+            .unwrap_or_default()
+            .into_values()
+            .flatten()
+            .collect()
+    }
+}
+
+impl<T> Default for SsrCollector<T>
+where
+    T: Clone + Send + Sync + PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    #[track_caller]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static> SsrContributor<T> {
+    /// Append an item to the ones provided by this contributor.
+    pub fn push(&self, item: T) {
+        self.signal_write
+            .update(|contributions| contributions.entry(self.index).or_default().push(item));
+    }
+
+    /// Replace all the items provided by this contributor.
+    pub fn set(&self, items: Vec<T>) {
+        self.signal_write.update(|contributions| {
+            contributions.insert(self.index, items);
+        });
+    }
+
+    /// Signal that this contributor is done providing items, releasing
+    /// its lock.  This is the same as dropping this contributor, as the
+    /// items already provided are kept.
+    pub fn finish(self) {
+        // the lock is released as the `ReadySender` is dropped.
+        drop(self);
+    }
+}
+
+impl<T: Debug, Ser> Debug for SsrCollector<T, Ser> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("SsrCollector")
+            .field("read_only", &self.inner.resource)
+            .field("contributions", &self.inner.signal_read)
+            .finish()
+    }
+}
+
+impl<T> Debug for SsrContributor<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("SsrContributor")
+            .field("index", &self.index)
+            .finish()
+    }
+}
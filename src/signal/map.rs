@@ -0,0 +1,183 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result},
+    hash::Hash,
+    sync::Arc,
+};
+
+use leptos::server::ArcResource;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{SsrSignalResource, SsrWriteSignal};
+
+/// Provides a keyed collection of [`SsrSignalResource`], where every key
+/// has its own lock.
+///
+/// Unlike a single `SsrSignalResource<HashMap<K, V>>` where every reader
+/// would have to wait for every writer, the readers of a given key will
+/// only wait for the writers of that same key.  The keys must all be
+/// declared as the map is created, where the pairing for every key is
+/// registered to the [`CoReadyCoordinator`](crate::CoReadyCoordinator)
+/// that was found at that point, such that the readers of the keys that
+/// never had a writer will be released with `None` as the coordinator
+/// notifies.
+///
+/// As every key is backed by its own resource, they are also hydrated
+/// independently of each other.  This is also why the keys must be
+/// declared up front, typically in the `setup` of the enclosing
+/// [`SyncSsrSignal`](crate::component::SyncSsrSignal): the resources are
+/// then created in the order the keys were declared, which is the same
+/// on the server and the client, rather than in the order the keys were
+/// first accessed, which would follow the order the readers resolved in
+/// under SSR.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_sync_ssr::{component::SyncSsrSignal, signal::SsrSignalMap};
+/// #
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <SyncSsrSignal setup=|| provide_context(SsrSignalMap::<u32, String>::new([1, 2]))>
+///             <Badge id=1 />
+///             <Badge id=2 />
+///             <Loader id=1 />
+///         </SyncSsrSignal>
+///     }
+/// }
+///
+/// #[component]
+/// fn Badge(id: u32) -> impl IntoView {
+///     let badge = expect_context::<SsrSignalMap<u32, String>>().read_key(id);
+///     view! {
+///         <Suspense>{move || {
+///             let badge = badge.clone();
+///             Suspend::new(async move { badge.await })
+///         }}</Suspense>
+///     }
+/// }
+///
+/// #[component]
+/// fn Loader(id: u32) -> impl IntoView {
+///     let map = expect_context::<SsrSignalMap<u32, String>>();
+///     let res = ArcResource::new(
+///         move || id,
+///         move |id| {
+///             let ws = map.write_key(id);
+///             async move {
+///                 ws.set(Some(format!("Item {id}")));
+///             }
+///         },
+///     );
+///     view! {
+///         <Suspense>{move || {
+///             let res = res.clone();
+///             Suspend::new(async move { res.await })
+///         }}</Suspense>
+///     }
+/// }
+/// ```
+///
+/// Note that this type can only be created inside components that have have
+/// the [`CoReadyCoordinator`](crate::ready::CoReadyCoordinator) be provided as
+/// a context, as per [`SsrSignalResource`].
+pub struct SsrSignalMap<K, V> {
+    inner: Arc<SsrSignalMapInner<K, V>>,
+}
+
+struct SsrSignalMapInner<K, V> {
+    // The declared keys in the order they were declared, for `Debug`.
+    keys: Vec<K>,
+    entries: HashMap<K, SsrSignalResource<Option<V>>>,
+}
+
+impl<K, V> Clone for SsrSignalMap<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<K, V> SsrSignalMap<K, V>
+where
+    K: Clone + Eq + Hash + Send + Sync + 'static,
+    V: Clone + Send + Sync + PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    /// Creates the map with the given keys, creating the pairing for
+    /// every key in the order they are given, with any repeated keys
+    /// ignored.
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.  This may be
+    /// resolved by providing the context by nesting this inside the
+    /// [`<SyncSsrSignal/>`](crate::component::SyncSsrSignal) component.
+    #[track_caller]
+    pub fn new(keys: impl IntoIterator<Item = K>) -> Self {
+        #[cfg(feature = "ssr")]
+        {
+            let location = std::panic::Location::caller();
+            if leptos::context::use_context::<crate::CoReadyCoordinator>().is_none() {
+                panic!("{location:?} expected a context of `CoReadyCoordinator` to be present")
+            }
+        }
+        let mut declared = Vec::new();
+        let mut entries = HashMap::new();
+        for key in keys {
+            if !entries.contains_key(&key) {
+                entries.insert(key.clone(), SsrSignalResource::new(None));
+                declared.push(key);
+            }
+        }
+        Self {
+            inner: Arc::new(SsrSignalMapInner {
+                keys: declared,
+                entries,
+            }),
+        }
+    }
+
+    /// Acquire the [`SsrSignalResource`] for the key, if it was declared.
+    pub fn key(&self, key: &K) -> Option<SsrSignalResource<Option<V>>> {
+        self.inner.entries.get(key).cloned()
+    }
+
+    /// Acquire the `ArcResource` for the key, as per
+    /// [`SsrSignalResource::read_only`].
+    ///
+    /// ## Panics
+    /// Panics if the key was not declared when this map was created.
+    #[track_caller]
+    pub fn read_key(&self, key: K) -> ArcResource<Option<V>> {
+        self.expect_key(&key).read_only()
+    }
+
+    /// Acquire the `SsrWriteSignal` for the key, as per
+    /// [`SsrSignalResource::write_only`], which also documents where this
+    /// should be acquired to ensure the readers of the key will wait for
+    /// the value.
+    ///
+    /// ## Panics
+    /// Panics if the key was not declared when this map was created.
+    #[track_caller]
+    pub fn write_key(&self, key: K) -> SsrWriteSignal<Option<V>> {
+        self.expect_key(&key).write_only()
+    }
+
+    #[track_caller]
+    fn expect_key(&self, key: &K) -> SsrSignalResource<Option<V>> {
+        let location = std::panic::Location::caller();
+        self.key(key).unwrap_or_else(|| {
+            panic!("{location:?} accessed a key not declared for `SsrSignalMap`")
+        })
+    }
+}
+
+impl<K: Debug, V> Debug for SsrSignalMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("SsrSignalMap")
+            .field("keys", &self.inner.keys)
+            .finish()
+    }
+}
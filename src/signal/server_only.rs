@@ -0,0 +1,189 @@
+use std::{
+    fmt::{Debug, Formatter, Result},
+    sync::Arc,
+};
+
+use leptos::{
+    prelude::{InnerHtmlAttribute, RenderHtml, Suspend},
+    reactive::{
+        computed::ArcAsyncDerived,
+        signal::{ArcReadSignal, ArcRwSignal, ArcWriteSignal},
+        traits::GetUntracked,
+    },
+    suspense::Suspense,
+    view, IntoView,
+};
+
+#[cfg(feature = "ssr")]
+use crate::ready::CoReady;
+
+use super::{SsrWriteSignal, SsrWriteSignalInner};
+
+/// Provides a signal-resource pairing like [`SsrSignalResource`](
+/// super::SsrSignalResource), but for values that are only ever rendered
+/// on the server.
+///
+/// The same waiting semantics under SSR apply, where the readers will
+/// wait until the [`SsrWriteSignal`] acquired through [`write_only`](
+/// SsrOnlySignalResource::write_only) provides the value or is dropped.
+/// However, the value is never serialized into the hydration payload,
+/// hence the value does not need to implement `Serialize` or
+/// `DeserializeOwned`, making this suitable for values that are purely
+/// presentational and never made interactive.
+///
+/// The value should be rendered through [`render`](
+/// SsrOnlySignalResource::render), which on the client will keep the DOM
+/// that was rendered on the server instead of rendering it again.  Do
+/// note that the client will not have the value, so when the view is
+/// rendered purely on the client (e.g. after a navigation under CSR),
+/// the rendered region will be empty.
+///
+/// Note that this type can only be created inside components that have have
+/// the [`CoReadyCoordinator`](crate::ready::CoReadyCoordinator) be provided as
+/// a context, as per [`SsrSignalResource`](super::SsrSignalResource).
+pub struct SsrOnlySignalResource<T> {
+    inner: Arc<SsrOnlySignalResourceInner<T>>,
+}
+
+struct SsrOnlySignalResourceInner<T> {
+    #[cfg(feature = "ssr")]
+    ready: CoReady,
+    derived: ArcAsyncDerived<T>,
+    signal_read: ArcReadSignal<T>,
+    signal_write: ArcWriteSignal<T>,
+}
+
+impl<T> Clone for SsrOnlySignalResource<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> SsrOnlySignalResource<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Creates a server-only signal-resource pairing with the value of
+    /// type `T`.
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.  This may be
+    /// resolved by providing the context by nesting this inside the
+    /// [`<SyncSsrSignal/>`](crate::component::SyncSsrSignal) component.
+    #[track_caller]
+    pub fn new(value: T) -> Self {
+        #[cfg(feature = "ssr")]
+        let ready = CoReady::new_inner(false, None);
+        let (signal_read, signal_write) = ArcRwSignal::new(value.clone()).split();
+        // Unlike `ArcResource`, an `ArcAsyncDerived` doesn't register itself
+        // with the shared context, so nothing will be serialized.
+        let derived = ArcAsyncDerived::new({
+            #[cfg(feature = "ssr")]
+            let ready = ready.clone();
+            let signal_read = signal_read.clone();
+            move || {
+                #[cfg(feature = "ssr")]
+                let subscriber = ready.subscribe();
+                let signal_read = signal_read.clone();
+                let value = value.clone();
+                async move {
+                    #[cfg(feature = "ssr")]
+                    subscriber.wait().await;
+                    signal_read.try_get_untracked().unwrap_or(value)
+                }
+            }
+        });
+        Self {
+            inner: Arc::new(SsrOnlySignalResourceInner {
+                #[cfg(feature = "ssr")]
+                ready,
+                derived,
+                signal_read,
+                signal_write,
+            }),
+        }
+    }
+
+    /// Acquire the underlying `ArcAsyncDerived`, which under SSR will
+    /// wait in the same manner as [`SsrSignalResource::read_only`](
+    /// super::SsrSignalResource::read_only).
+    ///
+    /// As its value is not serialized, using this directly to produce
+    /// the view will likely result in hydration errors; use [`render`](
+    /// SsrOnlySignalResource::render) for that instead.
+    pub fn read_only(&self) -> ArcAsyncDerived<T> {
+        self.inner.derived.clone()
+    }
+
+    /// Acquire a `SsrWriteSignal` for this pairing.  Refer to
+    /// [`SsrSignalResource::write_only`](
+    /// super::SsrSignalResource::write_only) for how it should be used.
+    pub fn write_only(&self) -> SsrWriteSignal<T> {
+        SsrWriteSignal {
+            inner: Arc::new(SsrWriteSignalInner {
+                signal_write: self.inner.signal_write.clone(),
+                #[cfg(feature = "ssr")]
+                ready_sender: self.inner.ready.to_ready_sender(),
+            }),
+        }
+    }
+
+    /// Returns the inner `ArcReadSignal`.  This bypasses the
+    /// asynchronous waiting mechanism ensured by the `ArcAsyncDerived`.
+    pub fn inner_read_only(&self) -> ArcReadSignal<T> {
+        self.inner.signal_read.clone()
+    }
+
+    /// Generate a view that renders the value with the provided function
+    /// under SSR, once the wait is over.
+    ///
+    /// The output is placed inside a `<div>` as its inner HTML, such that
+    /// on the client that `<div>` will be hydrated without touching its
+    /// content, keeping what was rendered on the server.
+    pub fn render<V>(&self, f: impl Fn(T) -> V + Send + Sync + 'static) -> impl IntoView
+    where
+        V: RenderHtml,
+    {
+        #[cfg(feature = "ssr")]
+        let result = {
+            let derived = self.inner.derived.clone();
+            let f = Arc::new(f);
+            view! {
+                <Suspense>{
+                    move || {
+                        let derived = derived.clone();
+                        let f = f.clone();
+                        Suspend::new(async move {
+                            let html = f(derived.await).to_html();
+                            view! { <div inner_html=html></div> }
+                        })
+                    }
+                }</Suspense>
+            }
+        };
+        #[cfg(not(feature = "ssr"))]
+        let result = {
+            let _ = f;
+            view! {
+                <Suspense>{
+                    move || Suspend::new(async move {
+                        view! { <div inner_html=String::new()></div> }
+                    })
+                }</Suspense>
+            }
+        };
+        result
+    }
+}
+
+impl<T> Debug for SsrOnlySignalResource<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("SsrOnlySignalResource")
+            .field("read_only", &self.inner.derived)
+            .field("write_only", &self.inner.signal_write)
+            .finish()
+    }
+}
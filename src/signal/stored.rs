@@ -0,0 +1,253 @@
+use std::{
+    fmt::{Debug, Formatter, Result},
+    future::Future,
+};
+
+use leptos::{
+    oco::Oco,
+    reactive::{
+        owner::ArenaItem,
+        signal::{ArcReadSignal, ArcWriteSignal},
+        traits::{Dispose, IsDisposed},
+    },
+    server::{codee::string::JsonSerdeCodec, Resource},
+    IntoView,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{ResourceCodec, SsrSignalResource, SsrWriteSignal};
+use crate::SealedError;
+
+/// An arena-allocated [`SsrSignalResource`], which is `Copy` and is
+/// disposed along with the reactive owner it was created under.
+///
+/// This relates to [`SsrSignalResource`] as `Resource` relates to
+/// `ArcResource`, such that closures may capture this by value without
+/// having to be cloned into them first.
+///
+/// ## Panics
+/// Accessing any of the methods after the owner was disposed will panic.
+pub struct StoredSsrSignalResource<T, Ser = JsonSerdeCodec>
+where
+    T: Send + Sync + 'static,
+{
+    inner: ArenaItem<SsrSignalResource<T, Ser>>,
+    // Created once along with the above, such that every `read_only`
+    // returns the same arena-allocated `Resource`.
+    resource: Resource<T, Ser>,
+}
+
+impl<T, Ser> Clone for StoredSsrSignalResource<T, Ser>
+where
+    T: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, Ser> Copy for StoredSsrSignalResource<T, Ser> where T: Send + Sync + 'static {}
+
+impl<T> StoredSsrSignalResource<T>
+where
+    T: Clone + Send + Sync + PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    /// Creates a signal-resource pairing as per [`SsrSignalResource::new`]
+    /// and stores it under the current reactive owner.
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.
+    #[track_caller]
+    pub fn new(value: T) -> Self {
+        SsrSignalResource::new(value).into()
+    }
+
+    /// Creates a named signal-resource pairing as per
+    /// [`SsrSignalResource::new_named`] and stores it under the current
+    /// reactive owner.
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.
+    #[track_caller]
+    pub fn new_named(name: impl Into<Oco<'static, str>>, value: T) -> Self {
+        SsrSignalResource::new_named(name, value).into()
+    }
+
+    /// Creates a signal-resource pairing as per
+    /// [`SsrSignalResource::new_must_notify`] and stores it under the
+    /// current reactive owner.
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.
+    #[track_caller]
+    pub fn new_must_notify(value: T) -> Self {
+        SsrSignalResource::new_must_notify(value).into()
+    }
+}
+
+impl<T, Ser> StoredSsrSignalResource<T, Ser>
+where
+    T: Clone + Send + Sync + PartialEq + 'static,
+    Ser: ResourceCodec<T>,
+{
+    /// Creates a signal-resource pairing as per
+    /// [`SsrSignalResource::new_with_codec`] and stores it under the
+    /// current reactive owner.
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.
+    #[track_caller]
+    pub fn new_with_codec(value: T) -> Self {
+        SsrSignalResource::new_with_codec(value).into()
+    }
+
+    /// Creates a named signal-resource pairing as per
+    /// [`SsrSignalResource::new_named_with_codec`] and stores it under
+    /// the current reactive owner.
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.
+    #[track_caller]
+    pub fn new_named_with_codec(name: impl Into<Oco<'static, str>>, value: T) -> Self {
+        SsrSignalResource::new_named_with_codec(name, value).into()
+    }
+
+    /// Creates a signal-resource pairing as per
+    /// [`SsrSignalResource::new_must_notify_with_codec`] and stores it
+    /// under the current reactive owner.
+    ///
+    /// ## Panics
+    /// Panics if the context of type `CoReadyCoordinator` is not found
+    /// in the current reactive owner or its ancestors.
+    #[track_caller]
+    pub fn new_must_notify_with_codec(value: T) -> Self {
+        SsrSignalResource::new_must_notify_with_codec(value).into()
+    }
+}
+
+impl<T, Ser> StoredSsrSignalResource<T, Ser>
+where
+    T: Send + Sync + 'static,
+    Ser: Send + Sync + 'static,
+{
+    /// Returns the stored [`SsrSignalResource`].
+    #[track_caller]
+    pub fn inner(&self) -> SsrSignalResource<T, Ser> {
+        self.inner
+            .try_get_value()
+            .expect("StoredSsrSignalResource accessed after being disposed")
+    }
+
+    /// Acquire the underlying resource as an arena-allocated `Resource`,
+    /// as per [`SsrSignalResource::read_only`].
+    ///
+    /// The `Resource` is stored along with the pairing, so this returns
+    /// the same `Resource` on every call.
+    pub fn read_only(&self) -> Resource<T, Ser> {
+        self.resource
+    }
+
+    /// Acquire a `SsrWriteSignal`, as per [`SsrSignalResource::write_only`].
+    #[track_caller]
+    pub fn write_only(&self) -> SsrWriteSignal<T> {
+        self.inner().write_only()
+    }
+
+    /// Acquire a `SsrWriteSignal` unless the coordinator was sealed, as
+    /// per [`SsrSignalResource::try_write_only`].
+    #[track_caller]
+    pub fn try_write_only(&self) -> std::result::Result<SsrWriteSignal<T>, SealedError> {
+        self.inner().try_write_only()
+    }
+
+    /// Returns the inner `ArcReadSignal`, as per
+    /// [`SsrSignalResource::inner_read_only`].
+    #[track_caller]
+    pub fn inner_read_only(&self) -> ArcReadSignal<T> {
+        self.inner().inner_read_only()
+    }
+
+    /// Returns the inner `ArcWriteSignal`, as per
+    /// [`SsrSignalResource::inner_write_only`].
+    #[track_caller]
+    pub fn inner_write_only(&self) -> ArcWriteSignal<T> {
+        self.inner().inner_write_only()
+    }
+
+    /// Generate the view that sets the value with the provided fetcher,
+    /// as per [`SsrSignalResource::set_with`].
+    #[track_caller]
+    pub fn set_with<Fut>(&self, fetcher: impl Fn() -> Fut + Send + Sync + 'static) -> impl IntoView
+    where
+        T: Clone,
+        Ser: ResourceCodec<T>,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        self.inner().set_with(fetcher)
+    }
+
+    /// Generate the view that updates the value with the provided fetcher
+    /// and updater, as per [`SsrSignalResource::update_with`].
+    #[track_caller]
+    pub fn update_with<Fut, U>(
+        &self,
+        fetcher: impl Fn() -> Fut + Send + Sync + 'static,
+        updater: impl Fn(&mut T, U) + Send + Sync + 'static,
+    ) -> impl IntoView
+    where
+        T: Clone,
+        Ser: ResourceCodec<T>,
+        Fut: Future<Output = U> + Send + 'static,
+        U: Send + 'static,
+    {
+        self.inner().update_with(fetcher, updater)
+    }
+}
+
+impl<T, Ser> From<SsrSignalResource<T, Ser>> for StoredSsrSignalResource<T, Ser>
+where
+    T: Send + Sync + 'static,
+    Ser: Send + Sync + 'static,
+{
+    fn from(value: SsrSignalResource<T, Ser>) -> Self {
+        Self {
+            resource: value.read_only().into(),
+            inner: ArenaItem::new(value),
+        }
+    }
+}
+
+impl<T, Ser> IsDisposed for StoredSsrSignalResource<T, Ser>
+where
+    T: Send + Sync + 'static,
+{
+    fn is_disposed(&self) -> bool {
+        self.inner.is_disposed()
+    }
+}
+
+impl<T, Ser> Dispose for StoredSsrSignalResource<T, Ser>
+where
+    T: Send + Sync + 'static,
+{
+    fn dispose(self) {
+        self.resource.dispose();
+        self.inner.dispose()
+    }
+}
+
+impl<T, Ser> Debug for StoredSsrSignalResource<T, Ser>
+where
+    T: Send + Sync + 'static,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("StoredSsrSignalResource")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
@@ -0,0 +1,197 @@
+use std::{
+    fmt::{Debug, Formatter, Result},
+    sync::{Arc, Mutex},
+};
+
+use leptos::{
+    prelude::{expect_context, provide_context},
+    reactive::{signal::ArcRwSignal, traits::Set, wrappers::read::ArcSignal},
+    task::spawn,
+};
+
+/// Holds back the values written by the writers of a newly matched route
+/// during CSR navigation, such that all of them are swapped in together.
+///
+/// Without this, navigating between routes under CSR would have the
+/// previous route [clear](crate::portlet::PortletCtx::clear) its portlets
+/// as it is cleaned up, followed by the writers of the new route filling
+/// them in as their fetches resolve, resulting in the portlets flashing
+/// empty and then being filled in piece by piece.  When this is provided
+/// as a context, typically as part of the `setup` for a
+/// [`SyncSsrSignal`](crate::component::SyncSsrSignal), the writes made
+/// through [`SsrSignalResource::set_with`](super::SsrSignalResource::set_with),
+/// [`SsrSignalResource::update_with`](super::SsrSignalResource::update_with)
+/// and [`PortletCtx::clear`](crate::portlet::PortletCtx::clear) under that
+/// scope are staged, with the previous values remaining visible until every
+/// writer has either resolved or been released (e.g. dropped as the route
+/// was navigated away before it resolved).  All the staged writes are then
+/// applied in the same batch, and the [`pending`](NavigationSwap::pending)
+/// signal may be used to show a loading hint in the meantime.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_sync_ssr::{
+/// #     component::SyncSsrSignal,
+/// #     portlet::PortletCtx,
+/// #     signal::NavigationSwap,
+/// # };
+/// #
+/// # #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+/// # struct Nav;
+/// #
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <SyncSsrSignal setup=|| {
+///             // provided before the portlets so they may capture it.
+///             NavigationSwap::provide();
+///             PortletCtx::<Nav>::provide();
+///         }>
+///             <Sidebar/>
+///         </SyncSsrSignal>
+///     }
+/// }
+///
+/// #[component]
+/// fn Sidebar() -> impl IntoView {
+///     let pending = NavigationSwap::expect().pending();
+///     view! {
+///         <aside class:loading=move || pending.get()>
+///             // the portlets are rendered here.
+///         </aside>
+///     }
+/// }
+/// ```
+///
+/// Note that the writers of the new route are expected to be created as
+/// part of the rendering of the route, as the staged writes are flushed
+/// once nothing has been pending for a tick.
+///
+/// *Under SSR*, there is no navigation to speak of, and the values are
+/// written directly with the locking as per `SsrSignalResource`.
+#[derive(Clone, Default)]
+pub struct NavigationSwap {
+    inner: Arc<Mutex<SwapState>>,
+    pending: ArcRwSignal<bool>,
+}
+
+#[derive(Default)]
+struct SwapState {
+    holds: usize,
+    staged: Vec<Box<dyn FnOnce() + Send>>,
+    scheduled: bool,
+}
+
+/// Keeps the [`NavigationSwap`] from applying the staged writes for as
+/// long as this is held.
+///
+/// Acquired through [`NavigationSwap::hold`], and released on drop.
+#[must_use = "the hold is released as soon as this is dropped"]
+pub struct SwapHold {
+    swap: NavigationSwap,
+}
+
+impl NavigationSwap {
+    /// Create a new `NavigationSwap` and provide it as a context to the
+    /// current reactive owner, returning it.
+    pub fn provide() -> Self {
+        let result = Self::default();
+        provide_context(result.clone());
+        result
+    }
+
+    /// Alias for [`expect_context::<NavigationSwap>()`](expect_context).
+    ///
+    /// ## Panics
+    /// Panics if `NavigationSwap` is not found in the current reactive
+    /// owner or its ancestors.
+    #[track_caller]
+    pub fn expect() -> Self {
+        expect_context::<Self>()
+    }
+
+    /// A signal that is `true` while there are writers yet to resolve or
+    /// writes yet to be applied, for use as a loading hint.
+    pub fn pending(&self) -> ArcSignal<bool> {
+        self.pending.clone().into()
+    }
+
+    /// Hold back the staged writes until the returned [`SwapHold`] is
+    /// dropped, for writers that are not managed by this crate.
+    pub fn hold(&self) -> SwapHold {
+        self.inner.lock().expect("mutex not panicked").holds += 1;
+        self.pending.set(true);
+        SwapHold { swap: self.clone() }
+    }
+
+    /// Stage the write, to be applied along with all other staged writes
+    /// once nothing is being held.
+    pub fn stage(&self, write: impl FnOnce() + Send + 'static) {
+        self.inner
+            .lock()
+            .expect("mutex not panicked")
+            .staged
+            .push(Box::new(write));
+        self.pending.set(true);
+        self.schedule();
+    }
+
+    // The flush is always deferred, such that the writers of a route
+    // that are created right after the previous route was cleaned up
+    // may acquire their holds before the cleared values are applied.
+    fn schedule(&self) {
+        {
+            let mut state = self.inner.lock().expect("mutex not panicked");
+            if state.scheduled {
+                return;
+            }
+            state.scheduled = true;
+        }
+        let this = self.clone();
+        spawn(async move { this.flush() });
+    }
+
+    fn flush(&self) {
+        let staged = {
+            let mut state = self.inner.lock().expect("mutex not panicked");
+            state.scheduled = false;
+            if state.holds > 0 {
+                return;
+            }
+            std::mem::take(&mut state.staged)
+        };
+        for write in staged {
+            write();
+        }
+        self.pending.set(false);
+    }
+}
+
+impl Drop for SwapHold {
+    fn drop(&mut self) {
+        let idle = {
+            let mut state = self.swap.inner.lock().expect("mutex not panicked");
+            state.holds -= 1;
+            state.holds == 0
+        };
+        if idle {
+            self.swap.schedule();
+        }
+    }
+}
+
+impl Debug for NavigationSwap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let state = self.inner.lock().expect("mutex not panicked");
+        f.debug_struct("NavigationSwap")
+            .field("holds", &state.holds)
+            .field("staged", &state.staged.len())
+            .finish()
+    }
+}
+
+impl Debug for SwapHold {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("SwapHold").finish_non_exhaustive()
+    }
+}
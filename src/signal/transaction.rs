@@ -0,0 +1,222 @@
+use std::{
+    fmt::{Debug, Formatter, Result},
+    sync::{Arc, Mutex},
+};
+
+use leptos::reactive::traits::{Notify, Write};
+
+use super::{SsrSignalResource, SsrWriteSignal};
+
+/// Groups the writes to several [`SsrSignalResource`] together, such that
+/// the readers of any of them are only released once the whole group is
+/// committed or aborted.
+///
+/// Writing to every resource through its own [`SsrWriteSignal`] releases
+/// the readers of each of them as soon as their value is set, so readers
+/// combining the values of several resources under SSR may observe them
+/// resolving at different moments.  Instead, the writers acquired through
+/// [`write`](SsrTransaction::write) only buffer the values set through
+/// them, and upon [`commit`](SsrTransaction::commit) all the buffered
+/// values are applied before any of the readers are released.  Should
+/// the transaction be [aborted](SsrTransaction::abort) or dropped, the
+/// buffered values are discarded, and the readers are released with the
+/// values the resources held.
+///
+/// Much like [`SsrSignalResource::write_only`], the transaction and its
+/// writers should be acquired inside a resource closure before any
+/// `.await` points, so that the readers under SSR will be waiting for it.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_sync_ssr::signal::{SsrSignalResource, SsrTransaction};
+/// #
+/// # async fn get_article() -> (String, String) {
+/// #     ("Title".to_string(), "Author".to_string())
+/// # }
+/// #
+/// #[component]
+/// fn Article() -> impl IntoView {
+///     let title = expect_context::<SsrSignalResource<String>>();
+///     let author = expect_context::<SsrSignalResource<Option<String>>>();
+///     let res = ArcResource::new(
+///         || (),
+///         move |_| {
+///             let tx = SsrTransaction::new();
+///             let title = tx.write(&title);
+///             let author = tx.write(&author);
+///             async move {
+///                 let (t, a) = get_article().await;
+///                 title.set(t);
+///                 author.set(Some(a));
+///                 // Readers of either resource are only released here.
+///                 tx.commit();
+///             }
+///         },
+///     );
+///     view! {
+///         <Suspense>{move || {
+///             let res = res.clone();
+///             Suspend::new(async move { res.await })
+///         }}</Suspense>
+///     }
+/// }
+/// ```
+///
+/// *Under CSR*, committing simply sets all the buffered values.
+// Note that this type is _NOT_ Clone, as the abort when dropped would
+// otherwise happen before the clones are done.
+pub struct SsrTransaction {
+    entries: Mutex<Vec<Box<dyn Pending>>>,
+}
+
+/// A writer that buffers the value for a [`SsrSignalResource`] that was
+/// made part of a [`SsrTransaction`].
+pub struct SsrTxWriteSignal<T> {
+    value: Arc<Mutex<Option<T>>>,
+}
+
+struct TxEntry<T> {
+    write: SsrWriteSignal<T>,
+    value: Arc<Mutex<Option<T>>>,
+}
+
+trait Pending: Send + Sync {
+    // Writes the buffered value without notifying, returning whether
+    // there was one.
+    fn apply(&self) -> bool;
+
+    fn release(self: Box<Self>, changed: bool);
+}
+
+impl<T: Send + Sync + 'static> Pending for TxEntry<T> {
+    fn apply(&self) -> bool {
+        match self.value.lock().expect("mutex not panicked").take() {
+            Some(value) => {
+                if let Some(mut guard) = self.write.try_write_untracked() {
+                    *guard = value;
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn release(self: Box<Self>, changed: bool) {
+        if changed {
+            self.write.notify();
+        } else {
+            // The `ReadySender` must be completed explicitly, as dropping
+            // it would not release the readers of a resource created with
+            // `SsrSignalResource::new_must_notify`.
+            #[cfg(feature = "ssr")]
+            if let Some(ready_sender) = &self.write.inner.ready_sender {
+                ready_sender.complete();
+            }
+        }
+    }
+}
+
+impl SsrTransaction {
+    /// Begin a new, empty transaction.
+    pub fn new() -> Self {
+        Self {
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Make the resource part of this transaction, returning the writer
+    /// that will buffer the value for it until the commit.
+    ///
+    /// This acquires a [`SsrWriteSignal`] for the resource as per
+    /// [`SsrSignalResource::write_only`], so its readers will be waiting
+    /// for this transaction to be committed or aborted.
+    pub fn write<T, Ser>(&self, resource: &SsrSignalResource<T, Ser>) -> SsrTxWriteSignal<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        let value = Arc::new(Mutex::new(None));
+        self.entries
+            .lock()
+            .expect("mutex not panicked")
+            .push(Box::new(TxEntry {
+                write: resource.write_only(),
+                value: value.clone(),
+            }));
+        SsrTxWriteSignal { value }
+    }
+
+    /// Apply every value buffered by the writers of this transaction,
+    /// and only then release the readers of all of the resources.
+    ///
+    /// The resources without a buffered value are released without any
+    /// changes.
+    pub fn commit(self) {
+        let entries = self.take();
+        let changed = entries
+            .iter()
+            .map(|entry| entry.apply())
+            .collect::<Vec<_>>();
+        for (entry, changed) in entries.into_iter().zip(changed) {
+            entry.release(changed);
+        }
+    }
+
+    /// Discard every value buffered by the writers of this transaction,
+    /// releasing the readers of all of the resources with the values the
+    /// resources currently hold.  This is the same as dropping this
+    /// transaction without committing.
+    pub fn abort(self) {}
+
+    fn take(&self) -> Vec<Box<dyn Pending>> {
+        std::mem::take(&mut *self.entries.lock().expect("mutex not panicked"))
+    }
+}
+
+impl Default for SsrTransaction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SsrTransaction {
+    fn drop(&mut self) {
+        for entry in self.take() {
+            entry.release(false);
+        }
+    }
+}
+
+impl<T> SsrTxWriteSignal<T> {
+    /// Buffer the value to be applied when the transaction commits,
+    /// replacing any value buffered before.
+    pub fn set(&self, value: T) {
+        *self.value.lock().expect("mutex not panicked") = Some(value);
+    }
+}
+
+impl<T> Clone for SsrTxWriteSignal<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl Debug for SsrTransaction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("SsrTransaction")
+            .field(
+                "writes",
+                &self.entries.lock().expect("mutex not panicked").len(),
+            )
+            .finish()
+    }
+}
+
+impl<T: Debug> Debug for SsrTxWriteSignal<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("SsrTxWriteSignal")
+            .field("value", &self.value)
+            .finish()
+    }
+}
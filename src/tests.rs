@@ -8,5 +8,9 @@ pub(crate) fn set_reactive_owner() -> Owner {
     owner
 }
 
+#[cfg(not(feature = "ssr"))]
+mod hydrate;
 #[cfg(feature = "ssr")]
 mod ready;
+#[cfg(not(feature = "ssr"))]
+mod signal;
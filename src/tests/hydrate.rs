@@ -0,0 +1,86 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use hydration_context::{PinnedFuture, PinnedStream, SerializedDataId, SharedContext};
+use leptos::{
+    error::{Error, ErrorId},
+    prelude::Owner,
+};
+
+// Serves the values as if they were serialized by the server, in the
+// order the resources are created as they are hydrated.
+#[derive(Debug)]
+struct Hydration {
+    data: Vec<String>,
+    next_id: AtomicUsize,
+}
+
+impl SharedContext for Hydration {
+    fn is_browser(&self) -> bool {
+        true
+    }
+
+    fn next_id(&self) -> SerializedDataId {
+        SerializedDataId::new(self.next_id.fetch_add(1, Ordering::Relaxed))
+    }
+
+    fn write_async(&self, _id: SerializedDataId, _fut: PinnedFuture<String>) {}
+
+    fn read_data(&self, id: &SerializedDataId) -> Option<String> {
+        self.data.get(id.clone().into_inner()).cloned()
+    }
+
+    fn await_data(&self, _id: &SerializedDataId) -> Option<String> {
+        None
+    }
+
+    fn pending_data(&self) -> Option<PinnedStream<String>> {
+        None
+    }
+
+    fn during_hydration(&self) -> bool {
+        true
+    }
+
+    fn hydration_complete(&self) {}
+
+    fn get_is_hydrating(&self) -> bool {
+        true
+    }
+
+    fn set_is_hydrating(&self, _is_hydrating: bool) {}
+
+    fn take_errors(&self) -> Vec<(SerializedDataId, ErrorId, Error)> {
+        Vec::new()
+    }
+
+    fn errors(&self, _boundary_id: &SerializedDataId) -> Vec<(ErrorId, Error)> {
+        Vec::new()
+    }
+
+    fn seal_errors(&self, _boundary_id: &SerializedDataId) {}
+
+    fn register_error(&self, _boundary: SerializedDataId, _id: ErrorId, _error: Error) {}
+
+    fn defer_stream(&self, _wait_for: PinnedFuture<()>) {}
+
+    fn await_deferred(&self) -> Option<PinnedFuture<()>> {
+        None
+    }
+
+    fn set_incomplete_chunk(&self, _id: SerializedDataId) {}
+
+    fn get_incomplete_chunk(&self, _id: &SerializedDataId) -> bool {
+        false
+    }
+}
+
+pub(crate) fn init_hydrate(data: &[&str]) -> Owner {
+    let _ = any_spawner::Executor::init_tokio();
+    Owner::new_root(Some(Arc::new(Hydration {
+        data: data.iter().map(|value| value.to_string()).collect(),
+        next_id: AtomicUsize::new(0),
+    })))
+}
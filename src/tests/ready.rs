@@ -1,16 +1,18 @@
 use std::time::Duration;
 
+use futures::StreamExt;
+use leptos::prelude::GetUntracked;
 use reactive_graph::owner::provide_context;
 use tokio::time::timeout;
 
 use super::set_reactive_owner;
-use crate::Ready;
+use crate::{Ready, ReadyState};
 
 #[tokio::test]
 async fn timeout_from_incomplete() -> anyhow::Result<()> {
     // Do actually demonstrate waiting will fail if Ready is provided
     let _owner = set_reactive_owner();
-    let ready = Ready::new();
+    let ready = Ready::new(None, None);
     provide_context(ready);
 
     let handle = Ready::handle();
@@ -29,7 +31,7 @@ async fn timeout_from_incomplete() -> anyhow::Result<()> {
 #[tokio::test]
 async fn wait_after_ready() {
     let _owner = set_reactive_owner();
-    let ready = Ready::new();
+    let ready = Ready::new(None, None);
     provide_context(ready.clone());
 
     let handle = Ready::handle();
@@ -50,7 +52,7 @@ async fn wait_after_ready() {
 #[tokio::test]
 async fn wait_before_ready() -> anyhow::Result<()> {
     let _owner = set_reactive_owner();
-    let ready = Ready::new();
+    let ready = Ready::new(None, None);
     provide_context(ready.clone());
 
     let handle = Ready::handle();
@@ -83,3 +85,56 @@ async fn without_context_no_waiting() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn readiness_observables() -> anyhow::Result<()> {
+    let _ = any_spawner::Executor::init_tokio();
+    let _owner = set_reactive_owner();
+    let ready = Ready::new(None, None);
+    provide_context(ready.clone());
+
+    let handle = Ready::handle();
+    let signal = handle.signal();
+    let shared = handle.shared();
+    let stream = handle.stream();
+    assert_eq!(handle.state(), ReadyState::Pending);
+    assert_eq!(signal.get_untracked(), ReadyState::Pending);
+
+    let task = tokio::spawn({
+        let shared = shared.clone();
+        async move {
+            timeout(Duration::from_millis(100), shared)
+                .await
+                .expect("shared future should not have timed out");
+        }
+    });
+    ready.complete();
+    task.await?;
+    timeout(Duration::from_millis(100), shared).await?;
+    assert_eq!(
+        stream.collect::<Vec<_>>().await,
+        [ReadyState::Pending, ReadyState::Ready]
+    );
+    assert_eq!(handle.state(), ReadyState::Ready);
+    assert_eq!(
+        handle.stream().collect::<Vec<_>>().await,
+        [ReadyState::Ready]
+    );
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    assert_eq!(signal.get_untracked(), ReadyState::Ready);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn readiness_without_context() {
+    let handle = Ready::handle();
+    assert_eq!(handle.state(), ReadyState::Ready);
+    assert_eq!(
+        handle.stream().collect::<Vec<_>>().await,
+        [ReadyState::Ready]
+    );
+    timeout(Duration::from_millis(100), handle.shared())
+        .await
+        .expect("shared future shouldn't wait here");
+}
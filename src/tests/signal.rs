@@ -0,0 +1,207 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use leptos::prelude::{ArcRwSignal, Get, GetUntracked, Set, Write};
+use reactive_graph::computed::ArcAsyncDerived;
+use tokio::time::timeout;
+
+use super::hydrate::init_hydrate;
+use crate::{
+    component::SyncScope,
+    signal::{SsrCollector, SsrSignalMap, SsrSignalResource},
+};
+
+#[tokio::test]
+async fn map_hydrate_keys_in_declared_order() {
+    // as serialized by the server, where only the first key was written.
+    let _owner = init_hydrate(&[r#""loaded 1""#, "null"]);
+    let map = SsrSignalMap::<u32, String>::new([1, 2]);
+    // the reader of the second key may well be hydrated before the first,
+    // such as when the readers resolved in that order under SSR.
+    assert_eq!(map.read_key(2).await, None);
+    assert_eq!(map.read_key(1).await, Some("loaded 1".to_string()));
+}
+
+#[tokio::test]
+async fn hydrate_adopts_server_value() {
+    // as serialized by the server after the value was set there.
+    let _owner = init_hydrate(&[r#""Hello world!""#]);
+    let sr = SsrSignalResource::new(String::new());
+    // no writes happened on the client.
+    assert_eq!(*sr.write_only().write_untracked(), "Hello world!");
+    assert_eq!(sr.inner_read_only().get_untracked(), "Hello world!");
+    assert_eq!(sr.read_only().await, "Hello world!");
+}
+
+#[tokio::test]
+async fn hydrate_without_server_value() {
+    let _owner = init_hydrate(&[]);
+    let sr = SsrSignalResource::new("Initial".to_string());
+    sr.write_only().set("Client".to_string());
+    tokio::task::yield_now().await;
+    assert_eq!(sr.inner_read_only().get_untracked(), "Client");
+    assert_eq!(sr.read_only().await, "Client");
+}
+
+// Drives the runs of `update_with` as its suspend would, where the runs
+// are repeated as the values tracked by the fetcher change, while counting
+// the fetches that were actually made.
+fn drive_update_with(
+    sr: &SsrSignalResource<Vec<String>>,
+    source: ArcRwSignal<&'static str>,
+) -> (ArcAsyncDerived<()>, Arc<AtomicUsize>) {
+    let fetches = Arc::new(AtomicUsize::new(0));
+    let run = sr.update_runs(
+        {
+            let fetches = fetches.clone();
+            move || {
+                let value = source.get();
+                let fetches = fetches.clone();
+                async move {
+                    fetches.fetch_add(1, Ordering::Relaxed);
+                    value.to_string()
+                }
+            }
+        },
+        |values, value| values.push(value),
+    );
+    (ArcAsyncDerived::new(run), fetches)
+}
+
+async fn changed(sr: &SsrSignalResource<Vec<String>>, len: usize) {
+    timeout(Duration::from_millis(500), async {
+        while sr.inner_read_only().get_untracked().len() != len {
+            tokio::task::yield_now().await;
+        }
+    })
+    .await
+    .expect("the change should have been applied");
+}
+
+#[tokio::test]
+async fn update_with_hydrated() {
+    // as serialized by the server for the signal and then for the writer.
+    let _owner = init_hydrate(&[r#"["From server"]"#, "null"]);
+    let sr = SsrSignalResource::new(Vec::new());
+    let source = ArcRwSignal::new("From client");
+    let (runs, fetches) = drive_update_with(&sr, source.clone());
+
+    runs.clone().await;
+    // neither fetched again nor applied on top of the adopted value.
+    assert_eq!(fetches.load(Ordering::Relaxed), 0);
+    assert_eq!(sr.inner_read_only().get_untracked(), ["From server"]);
+
+    source.set("Changed");
+    changed(&sr, 2).await;
+    assert_eq!(fetches.load(Ordering::Relaxed), 1);
+    assert_eq!(
+        sr.inner_read_only().get_untracked(),
+        ["From server", "Changed"]
+    );
+}
+
+#[tokio::test]
+async fn update_with_not_hydrated() {
+    let _owner = init_hydrate(&[]);
+    let sr = SsrSignalResource::new(Vec::new());
+    let source = ArcRwSignal::new("From client");
+    let (runs, fetches) = drive_update_with(&sr, source.clone());
+
+    runs.clone().await;
+    // the value fetched by the resource is applied, and only once.
+    assert_eq!(fetches.load(Ordering::Relaxed), 1);
+    assert_eq!(sr.inner_read_only().get_untracked(), ["From client"]);
+
+    source.set("Changed");
+    changed(&sr, 2).await;
+    assert_eq!(fetches.load(Ordering::Relaxed), 2);
+}
+
+#[cfg(feature = "serde-lite")]
+#[tokio::test]
+async fn hydrate_serde_lite() {
+    use leptos::server::codee::{string::JsonSerdeCodec, SerdeLite};
+
+    let _owner = init_hydrate(&[r#""Hello lite!""#]);
+    let sr = SsrSignalResource::<String, SerdeLite<JsonSerdeCodec>>::new_with_codec(String::new());
+    assert_eq!(sr.inner_read_only().get_untracked(), "Hello lite!");
+    assert_eq!(sr.read_only().await, "Hello lite!");
+}
+
+#[tokio::test]
+async fn collector_contributor_cleaned_up() {
+    let owner = init_hydrate(&[]);
+    let collector = SsrCollector::<String>::new();
+    collector.contributor().push("Kept".to_string());
+    let child = owner.child();
+    child.with(|| {
+        let contributor = collector.contributor();
+        contributor.push("Unmounted".to_string());
+        contributor.finish();
+    });
+    // finishing the contributor keeps the items it provided.
+    assert_eq!(collector.get_untracked(), ["Kept", "Unmounted"]);
+    // as the component that acquired the contributor is unmounted.
+    child.cleanup();
+    assert_eq!(collector.get_untracked(), ["Kept"]);
+}
+
+#[tokio::test]
+async fn contribute_with_fetched_once() {
+    let _owner = init_hydrate(&[]);
+    let collector = SsrCollector::<String>::new();
+    let source = ArcRwSignal::new("From client");
+    let fetches = Arc::new(AtomicUsize::new(0));
+    let run = collector.contribute_runs({
+        let fetches = fetches.clone();
+        let source = source.clone();
+        move || {
+            let value = source.get();
+            let fetches = fetches.clone();
+            async move {
+                fetches.fetch_add(1, Ordering::Relaxed);
+                vec![value.to_string()]
+            }
+        }
+    });
+    let runs = ArcAsyncDerived::new(run);
+
+    runs.clone().await;
+    assert_eq!(fetches.load(Ordering::Relaxed), 1);
+    assert_eq!(collector.get_untracked(), ["From client"]);
+
+    source.set("Changed");
+    timeout(Duration::from_millis(500), async {
+        while collector.get_untracked() != ["Changed"] {
+            tokio::task::yield_now().await;
+        }
+    })
+    .await
+    .expect("the change should have been contributed");
+    // only fetched by the run, not also by the resource.
+    tokio::task::yield_now().await;
+    assert_eq!(fetches.load(Ordering::Relaxed), 2);
+}
+
+#[tokio::test]
+async fn async_setup_not_run() {
+    let _owner = init_hydrate(&[]);
+    let called = Arc::new(AtomicUsize::new(0));
+    let _handle = SyncScope::new()
+        .async_setup({
+            let called = called.clone();
+            move || {
+                called.fetch_add(1, Ordering::Relaxed);
+                async {}
+            }
+        })
+        .enter();
+    tokio::task::yield_now().await;
+    // the values it would write were already hydrated.
+    assert_eq!(called.load(Ordering::Relaxed), 0);
+}
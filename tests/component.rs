@@ -4,6 +4,10 @@ use leptos_sync_ssr::{component::SyncSsr, Ready};
 #[cfg(feature = "ssr")]
 mod ssr {
     pub use futures::StreamExt;
+    pub use leptos_sync_ssr::{
+        component::{SyncScope, SyncSsrScope, WaitReady},
+        CoReadyCoordinator,
+    };
 }
 #[cfg(feature = "ssr")]
 use ssr::*;
@@ -81,6 +85,132 @@ fn StandardSsr() -> impl IntoView {
     }
 }
 
+#[cfg(feature = "ssr")]
+#[component]
+fn WaitingIndicator(#[prop(optional, into)] name: Option<&'static str>) -> impl IntoView {
+    let rs = expect_context::<ReadSignal<Option<String>>>();
+    let children = move || view! { <p>"Indicator is: "{move || rs.get()}</p> };
+    match name {
+        Some(name) => view! { <WaitReady name>{children}</WaitReady> }.into_any(),
+        None => view! { <WaitReady>{children}</WaitReady> }.into_any(),
+    }
+}
+
+#[component]
+fn SignalSetter() -> impl IntoView {
+    let ws = expect_context::<WriteSignal<Option<String>>>();
+    move || ws.set(Some("hello world".to_string()))
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn render_wait_ready() {
+    let _owner = init_renderer();
+    let (rs, ws) = signal(None::<String>);
+    provide_context(rs);
+    provide_context(ws);
+    let app = view! {
+        <SyncSsr>
+            <WaitingIndicator />
+            <SignalSetter />
+        </SyncSsr>
+    };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("Indicator is: <!>hello world"));
+}
+
+#[component]
+fn SpawnedSetter() -> impl IntoView {
+    let ws = expect_context::<WriteSignal<Option<String>>>();
+    let guard = Ready::hold();
+    leptos::task::spawn(async move {
+        #[cfg(feature = "ssr")]
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        ws.set(Some("hello spawned".to_string()));
+        drop(guard);
+    });
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn render_wait_ready_held() {
+    let _owner = init_renderer();
+    let (rs, ws) = signal(None::<String>);
+    provide_context(rs);
+    provide_context(ws);
+    let app = view! {
+        <SyncSsr>
+            <WaitingIndicator />
+            <SpawnedSetter />
+        </SyncSsr>
+    };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("Indicator is: <!>hello spawned"));
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn render_wait_ready_named() {
+    let _owner = init_renderer();
+    let (rs, ws) = signal(None::<String>);
+    provide_context(rs);
+    provide_context(ws);
+    let app = view! {
+        <SyncSsr name="outer">
+            <SyncSsr>
+                // waiting on the nearest would not see the value
+                <WaitingIndicator name="outer" />
+            </SyncSsr>
+            <SignalSetter />
+        </SyncSsr>
+    };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("Indicator is: <!>hello world"));
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn render_sync_ssr_scope() {
+    let _owner = init_renderer();
+    let app = view! {
+        <SyncSsrScope setup=|| {
+            let (rs, ws) = signal(None::<String>);
+            provide_context(rs);
+            provide_context(ws);
+        }>
+            <WaitingIndicator />
+            <SignalSetter />
+            {use_context::<CoReadyCoordinator>().map(|_| "with coordinator")}
+        </SyncSsrScope>
+    };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("Indicator is: <!>hello world"));
+    assert!(html.contains("with coordinator"));
+}
+
+#[cfg(feature = "ssr")]
+#[component]
+fn EnteredScope() -> impl IntoView {
+    let (rs, ws) = signal(None::<String>);
+    provide_context(rs);
+    provide_context(ws);
+    let handle = SyncScope::new().enter();
+    view! {
+        <WaitingIndicator />
+        <SignalSetter />
+        {move || handle.complete()}
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn render_entered_scope() {
+    let _owner = init_renderer();
+    let app = view! { <EnteredScope /> };
+    let html = app.to_html_stream_in_order().collect::<String>().await;
+    assert!(html.contains("Indicator is: <!>hello world"));
+}
+
 #[cfg(feature = "ssr")]
 #[tokio::test]
 async fn render_synced_ssr() {
@@ -0,0 +1,303 @@
+use leptos::prelude::*;
+use leptos_sync_ssr::{signal::SsrSignalResource, CoReadyCoordinator, Ready};
+
+#[cfg(feature = "ssr")]
+mod ssr {
+    pub use futures::StreamExt;
+    pub use leptos_sync_ssr::{
+        component::{DeclareWriters, SyncSsrSignal},
+        observer::{Release, StallEvent, SyncObserver, WaitEvent, WaitKind},
+    };
+    pub use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+}
+#[cfg(feature = "ssr")]
+use ssr::*;
+
+#[cfg(feature = "ssr")]
+#[derive(Default)]
+struct Recorder {
+    created: Mutex<usize>,
+    released: Mutex<Vec<Release>>,
+    waits: Mutex<Vec<WaitKind>>,
+    late: Mutex<Vec<Option<String>>>,
+    stalled: Mutex<Vec<Option<String>>>,
+}
+
+#[cfg(feature = "ssr")]
+impl SyncObserver for Recorder {
+    fn wait(&self, event: WaitEvent) {
+        self.waits.lock().unwrap().push(event.kind);
+    }
+
+    fn co_ready_created(&self) {
+        *self.created.lock().unwrap() += 1;
+    }
+
+    fn co_ready_released(&self, release: Release) {
+        self.released.lock().unwrap().push(release);
+    }
+
+    fn late_writer(&self, name: Option<Oco<'static, str>>) {
+        self.late
+            .lock()
+            .unwrap()
+            .push(name.map(|name| name.to_string()));
+    }
+
+    fn ready_stalled(&self, event: StallEvent) {
+        self.stalled
+            .lock()
+            .unwrap()
+            .push(event.name.map(|name| name.to_string()));
+    }
+}
+
+#[component]
+fn Indicator() -> impl IntoView {
+    let res = expect_context::<SsrSignalResource<String>>().read_only();
+    view! {
+        <Suspense>
+        {move || {
+            let res = res.clone();
+            Suspend::new(async move {
+                res.await
+            })
+        }}
+        </Suspense>
+    }
+}
+
+#[component]
+fn Setter() -> impl IntoView {
+    let sr = expect_context::<SsrSignalResource<String>>();
+    view! {
+        {sr.set_with(|| async move {
+            #[cfg(feature = "ssr")]
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            "Hello world!".to_string()
+        })}
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn observe_released_by_write() {
+    let _owner = init_renderer();
+    let recorder = Arc::new(Recorder::default());
+    let observer: Arc<dyn SyncObserver> = recorder.clone();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrSignalResource::new(String::new()));
+        } observer>
+            <Indicator />
+            <Setter />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!>Hello world!<!><!>",
+    );
+    assert_eq!(*recorder.created.lock().unwrap(), 1);
+    assert_eq!(*recorder.released.lock().unwrap(), [Release::Write]);
+    assert!(recorder
+        .waits
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|kind| *kind == WaitKind::CoReady));
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn observe_released_by_notify() {
+    let _owner = init_renderer();
+    let recorder = Arc::new(Recorder::default());
+    leptos_sync_ssr::observer::provide_sync_observer(recorder.clone());
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrSignalResource::new(String::new()));
+        }>
+            <Indicator />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!> <!>",
+    );
+    assert_eq!(*recorder.created.lock().unwrap(), 1);
+    assert_eq!(*recorder.released.lock().unwrap(), [Release::Notify]);
+    assert_eq!(*recorder.waits.lock().unwrap(), [WaitKind::CoReady]);
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn observe_released_undeclared() {
+    let _owner = init_renderer();
+    let recorder = Arc::new(Recorder::default());
+    let observer: Arc<dyn SyncObserver> = recorder.clone();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrSignalResource::new_named("greeting", String::new()));
+        } observer>
+            <Indicator />
+            <DeclareWriters names=vec!["other".into()] />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!> <!><!>",
+    );
+    assert_eq!(*recorder.released.lock().unwrap(), [Release::Undeclared]);
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn observe_declared_writer() {
+    let _owner = init_renderer();
+    let recorder = Arc::new(Recorder::default());
+    let observer: Arc<dyn SyncObserver> = recorder.clone();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrSignalResource::new_named("greeting", String::new()));
+        } observer>
+            <Indicator />
+            <DeclareWriters names=vec!["greeting".into()] />
+            <Setter />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!>Hello world!<!><!><!>",
+    );
+    assert_eq!(*recorder.released.lock().unwrap(), [Release::Write]);
+}
+
+#[component]
+fn Sealer() -> impl IntoView {
+    let handle = CoReadyCoordinator::handle();
+    assert!(!handle.is_sealed());
+    handle.seal();
+    assert!(handle.is_sealed());
+}
+
+#[component]
+fn LateWriter() -> impl IntoView {
+    let sr = expect_context::<SsrSignalResource<String>>();
+    let Err(err) = sr.try_write_only() else {
+        panic!("coordinator was sealed");
+    };
+    assert_eq!(err.name(), Some("greeting"));
+    // this is still acquired, but only reported, and the readers will not
+    // be waiting for it.
+    let ws = sr.write_only();
+    leptos::task::spawn(async move {
+        #[cfg(feature = "ssr")]
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        ws.set("Too late".to_string());
+    });
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn observe_sealed() {
+    let _owner = init_renderer();
+    let recorder = Arc::new(Recorder::default());
+    let observer: Arc<dyn SyncObserver> = recorder.clone();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrSignalResource::new_named("greeting", String::new()));
+        } observer>
+            <Indicator />
+            <Sealer />
+            <LateWriter />
+            <Indicator />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!> <!><!> <!>",
+    );
+    assert_eq!(*recorder.released.lock().unwrap(), [Release::Sealed]);
+    assert_eq!(
+        *recorder.late.lock().unwrap(),
+        [Some("greeting".to_string())]
+    );
+}
+
+#[component]
+fn SlowHolder() -> impl IntoView {
+    let guard = Ready::hold();
+    leptos::task::spawn(async move {
+        #[cfg(feature = "ssr")]
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        drop(guard);
+    });
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn observe_ready_stalled() {
+    use leptos_sync_ssr::component::SyncSsr;
+
+    let _owner = init_renderer();
+    let recorder = Arc::new(Recorder::default());
+    let observer: Arc<dyn SyncObserver> = recorder.clone();
+
+    let app = view! {
+        <SyncSsr name="region" observer stall_deadline=Duration::from_millis(20)>
+            <SlowHolder />
+        </SyncSsr>
+    };
+    let _ = app.to_html_stream_in_order().collect::<String>().await;
+    // the guard is still held, so the `Ready` has yet to complete.
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(
+        *recorder.stalled.lock().unwrap(),
+        [Some("region".to_string())]
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn server_timing_entries() {
+    use leptos_sync_ssr::{component::SyncSsr, observer::ServerTiming};
+
+    let _owner = init_renderer();
+    let timing = ServerTiming::provide();
+
+    let app = view! {
+        <SyncSsr name="region">
+            <SyncSsrSignal setup=|| {
+                provide_context(SsrSignalResource::new_named("greeting", String::new()));
+            }>
+                <Indicator />
+                <Setter />
+            </SyncSsrSignal>
+        </SyncSsr>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!>Hello world!<!><!><!>",
+    );
+    let entries = timing.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].0, "greeting");
+    assert!(entries[0].1 >= std::time::Duration::from_millis(50));
+    assert!(timing.header_value().starts_with("greeting;dur="));
+}
+
+#[cfg(feature = "ssr")]
+fn init_renderer() -> Owner {
+    let _ = any_spawner::Executor::init_tokio();
+    let owner = Owner::new();
+    owner.set();
+    owner
+}
@@ -1,12 +1,16 @@
 use std::time::Duration;
 
 use leptos::prelude::*;
-use leptos_sync_ssr::{component::SyncSsrSignal, portlet::PortletCtx};
+use leptos_sync_ssr::{
+    component::SyncSsrSignal,
+    portlet::{DefaultedPortletCtx, PortletCtx, PortletRegistry, StoredPortletCtx},
+};
 
 #[cfg(feature = "ssr")]
 mod ssr {
     pub use futures::StreamExt;
     use leptos::prelude::Owner;
+    pub use leptos_sync_ssr::portlet::PortletRegion;
 
     pub fn init_renderer() -> Owner {
         let _ = any_spawner::Executor::init_tokio();
@@ -67,3 +71,380 @@ async fn portlet_setter() {
         "<!>Hello world!<!><!>",
     );
 }
+
+#[component]
+pub fn StoredSetter() -> impl IntoView {
+    let ctx = <StoredPortletCtx<Item>>::expect();
+    let message = StoredValue::new("Hello stored!".to_string());
+    // the `Copy` context may be captured by multiple closures directly.
+    on_cleanup(move || ctx.clear());
+
+    view! {
+        {ctx.set_with(move || {
+            async move {
+                #[cfg(feature = "ssr")]
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                Some(Item(message.get_value()))
+            }
+        })}
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn stored_portlet_setter() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| Ctx::provide()>
+            <Portlet />
+            <StoredSetter />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!>Hello stored!<!><!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn portlet_default_without_setter() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| Ctx::provide_with(Item("Default".to_string()))>
+            <Portlet />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!>Default<!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn portlet_default_with_setter() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| Ctx::provide_with(Item("Default".to_string()))>
+            <Portlet />
+            <Setter />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!>Hello world!<!><!>",
+    );
+}
+
+#[component]
+pub fn Clearer() -> impl IntoView {
+    let ctx = Ctx::expect();
+    ctx.inner_write_signal()
+        .set(Some(Item("Changed".to_string())));
+    ctx.clear();
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn portlet_default_clear() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| Ctx::provide_with(Item("Default".to_string()))>
+            <Clearer />
+            <Portlet />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!><!>Default<!>",
+    );
+}
+
+#[component]
+pub fn DefaultReader() -> impl IntoView {
+    let ctx = DefaultedPortletCtx::<Item>::expect();
+    view! {
+        <p><Suspense>{move || {
+            let value = ctx.read();
+            Suspend::new(async move { value.await.0 })
+        }}</Suspense></p>
+    }
+}
+
+#[component]
+pub fn NoneSetter() -> impl IntoView {
+    let ctx = Ctx::expect();
+
+    view! {
+        {ctx.set_with(move || {
+            async move {
+                #[cfg(feature = "ssr")]
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                None
+            }
+        })}
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn portlet_read_with_default() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| Ctx::provide_with(Item("Default".to_string()))>
+            <DefaultReader />
+            <NoneSetter />
+        </SyncSsrSignal>
+    };
+    // the `None` written is read as the default.
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!><p>Default</p><!><!>",
+    );
+
+    let app = view! {
+        <SyncSsrSignal setup=|| Ctx::provide_with(Item("Default".to_string()))>
+            <DefaultReader />
+            <Setter />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!><p>Hello world!</p><!><!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn portlet_read_without_default() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            Ctx::provide();
+            assert!(use_context::<DefaultedPortletCtx<Item>>().is_none());
+        }>
+            <Portlet />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!><!><!>",
+    );
+}
+
+#[component]
+pub fn SlotSetter(name: &'static str, value: &'static str) -> impl IntoView {
+    let ctx = Ctx::expect_slot(name);
+
+    view! {
+        {ctx.set_with(move || {
+            async move {
+                #[cfg(feature = "ssr")]
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                Some(Item(value.to_string()))
+            }
+        })}
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn portlet_slots() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            Ctx::provide_slot("left");
+            Ctx::provide_slot("right");
+        }>
+            <nav>{Ctx::render_slot("left")}</nav>
+            <nav>{Ctx::render_slot("right")}</nav>
+            <SlotSetter name="right" value="Right side" />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!><nav><!></nav><nav>Right side</nav><!><!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+#[should_panic]
+fn portlet_slot_missing() {
+    let _owner = init_renderer();
+    let _ = Ctx::expect_slot("missing");
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Count(u32);
+
+#[component]
+pub fn RegistrySetter() -> impl IntoView {
+    let ctx = PortletRegistry::expect()
+        .portlet::<Item>("item")
+        .expect("registered as Item");
+
+    view! {
+        {ctx.set_with(move || {
+            async move {
+                #[cfg(feature = "ssr")]
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                Some(Item("Registered".to_string()))
+            }
+        })}
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn portlet_registry_region() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            let registry = PortletRegistry::provide();
+            registry.register("count", 2, |count: Count| {
+                view! { <p>"Count "{count.0}</p> }.into_any()
+            });
+            registry.register("item", 1, |item: Item| {
+                view! { <p>{item.0}</p> }.into_any()
+            });
+            assert!(registry.portlet::<Count>("item").is_none());
+        }>
+            <aside><PortletRegion /></aside>
+            <RegistrySetter />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!><aside><p>Registered</p><!><!></aside><!><!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn portlet_registry_register_again() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            let registry = PortletRegistry::provide();
+            registry.register("item", 1, |item: Item| {
+                view! { <p>{item.0}</p> }.into_any()
+            });
+            // the portlet registered first is kept, so the writers that
+            // acquired it will still release the region.
+            registry.register("item", 2, |item: Item| {
+                view! { <b>{item.0}</b> }.into_any()
+            });
+        }>
+            <aside><PortletRegion /></aside>
+            <RegistrySetter />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        tokio::time::timeout(
+            Duration::from_millis(500),
+            app.to_html_stream_in_order().collect::<String>()
+        )
+        .await
+        .expect("the region should not wait on a replaced portlet"),
+        "<!><aside><p>Registered</p><!></aside><!><!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn portlet_registry_without_coordinator() {
+    let _owner = init_renderer();
+
+    let registry = PortletRegistry::default();
+    let result = std::panic::catch_unwind(|| {
+        registry.register("item", 1, |item: Item| item.0.into_any());
+    });
+    assert!(result.is_err());
+    // the registry remains usable after the failed registration.
+    assert!(registry.portlet::<Item>("item").is_none());
+    assert!(!registry.unregister("item"));
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+#[should_panic(expected = "with a different type")]
+async fn portlet_registry_register_other_type() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            let registry = PortletRegistry::provide();
+            registry.register("item", 1, |item: Item| item.0.into_any());
+            registry.register("item", 1, |count: Count| count.0.into_any());
+        }>
+            <PortletRegion />
+        </SyncSsrSignal>
+    };
+    let _ = app.to_html_stream_in_order().collect::<String>().await;
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn portlet_override_nested() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| Ctx::provide()>
+            <Portlet />
+            <SyncSsrSignal setup=|| Ctx::provide_override()>
+                <Setter />
+            </SyncSsrSignal>
+        </SyncSsrSignal>
+    };
+    // the outer reader waited for the writer in the nested scope.
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!>Hello world!<!><!><!><!>",
+    );
+}
+
+#[component]
+pub fn OuterSetter() -> impl IntoView {
+    let ctx = Ctx::expect();
+
+    view! {
+        {ctx.set_with(move || async move { Some(Item("Outer".to_string())) })}
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn portlet_override_without_writer() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| Ctx::provide()>
+            <Portlet />
+            <OuterSetter />
+            <SyncSsrSignal setup=|| Ctx::provide_override()>
+                <p>"Nested"</p>
+            </SyncSsrSignal>
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        tokio::time::timeout(
+            Duration::from_millis(500),
+            app.to_html_stream_in_order().collect::<String>()
+        )
+        .await
+        .expect("the override should release the outer portlet"),
+        "<!>Outer<!><!><p>Nested</p><!><!>",
+    );
+}
@@ -1,12 +1,18 @@
 use std::time::Duration;
 
-use leptos::prelude::*;
-use leptos_sync_ssr::{component::SyncSsrSignal, signal::SsrSignalResource};
+use leptos::{prelude::*, server::codee::binary::FromToBytesCodec};
+use leptos_sync_ssr::{
+    component::SyncSsrSignal,
+    signal::{SsrOnlySignalResource, SsrSignalResource, StoredSsrSignalResource},
+};
 use tokio::time::timeout;
 
 #[cfg(feature = "ssr")]
 mod ssr {
     pub use futures::StreamExt;
+    pub use leptos_sync_ssr::signal::{
+        NavigationSwap, SsrCollector, SsrOnceResource, SsrSignalMap, SsrTransaction,
+    };
 }
 #[cfg(feature = "ssr")]
 use ssr::*;
@@ -487,6 +493,570 @@ async fn render_indicator_only() {
     );
 }
 
+// Render what the resource resolves to under a `Suspense`.
+fn suspended<T, Ser>(res: ArcResource<T, Ser>) -> impl IntoView
+where
+    T: IntoView + Clone + Send + Sync + 'static,
+    Ser: Send + Sync + 'static,
+{
+    view! {
+        <Suspense>
+        {move || {
+            let res = res.clone();
+            Suspend::new(async move { res.await })
+        }}
+        </Suspense>
+    }
+}
+
+// Run the fetcher through a resource rendered under a `Suspense`, such
+// that the rendering waits on whatever is written inside it.
+fn fetch_view<T, Fut>(fetcher: impl Fn() -> Fut + Send + Sync + 'static) -> impl IntoView
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+    T: IntoView + Clone + Send + Sync + 'static,
+    Fut: std::future::Future<Output = T> + Send + 'static,
+{
+    suspended(ArcResource::new(|| (), move |_| fetcher()))
+}
+
+#[cfg(feature = "ssr")]
+#[component]
+fn OnceIndicator() -> impl IntoView {
+    let res = expect_context::<SsrOnceResource<String>>().read_only();
+    view! {
+        <p>
+            "Once is: "
+            {suspended(res)}
+        </p>
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[component]
+fn OnceProducer(send: bool) -> impl IntoView {
+    let once = expect_context::<SsrOnceResource<String>>();
+    fetch_view(move || {
+        let sender = once.sender();
+        let other = once.sender();
+        async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            if send {
+                assert_eq!(sender.send("Hello world!".to_string()), Ok(()));
+                assert_eq!(other.send("Again".to_string()), Err("Again".to_string()));
+                "sent".to_string()
+            } else {
+                "dropped".to_string()
+            }
+        }
+    })
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn once_sent() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrOnceResource::new("default".to_string()));
+        }>
+            <OnceIndicator />
+            <OnceProducer send=true />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!><p>Once is: <!>Hello world!</p>sent<!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn once_dropped() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrOnceResource::new("default".to_string()));
+        }>
+            <OnceIndicator />
+            <OnceProducer send=false />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!><p>Once is: <!>default</p>dropped<!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[component]
+fn Badge(id: u32) -> impl IntoView {
+    let res = expect_context::<SsrSignalMap<u32, String>>().read_key(id);
+    view! {
+        <p>
+            {format!("Badge {id}: ")}
+            <Suspense>
+            {move || {
+                let res = res.clone();
+                Suspend::new(async move {
+                    res.await.unwrap_or_else(|| "none".to_string())
+                })
+            }}
+            </Suspense>
+        </p>
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[component]
+fn BadgeLoader(id: u32) -> impl IntoView {
+    let map = expect_context::<SsrSignalMap<u32, String>>();
+    fetch_view(move || {
+        let ws = map.write_key(id);
+        async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            ws.set(Some(format!("loaded {id}")));
+        }
+    })
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn map_per_key() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrSignalMap::<u32, String>::new([1, 2]));
+        }>
+            <Badge id=1 />
+            <Badge id=2 />
+            <BadgeLoader id=1 />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!><p>Badge 1: <!>loaded 1</p><p>Badge 2: <!>none</p><!><!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[component]
+fn LateBadge(id: u32) -> impl IntoView {
+    let map = expect_context::<SsrSignalMap<u32, String>>();
+    view! {
+        <Suspense>
+        {move || {
+            let map = map.clone();
+            Suspend::new(async move {
+                // the key is only read after the coordinator notified.
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                map.read_key(id).await.unwrap_or_else(|| "none".to_string())
+            })
+        }}
+        </Suspense>
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn map_key_read_after_notify() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrSignalMap::<u32, String>::new([3]));
+        }>
+            <LateBadge id=3 />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        timeout(
+            Duration::from_millis(500),
+            app.to_html_stream_in_order().collect::<String>()
+        )
+        .await
+        .expect("key read after notify should not deadlock"),
+        "<!>none<!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+#[should_panic(expected = "key not declared")]
+async fn map_key_undeclared() {
+    let _owner = init_renderer();
+    let _ = view! {
+        <SyncSsrSignal setup=|| {
+            let map = SsrSignalMap::<u32, String>::new([1]);
+            let _ = map.read_key(2);
+        }>
+            <p>"Unreachable"</p>
+        </SyncSsrSignal>
+    };
+}
+
+#[component]
+fn BytesIndicator() -> impl IntoView {
+    suspended(expect_context::<SsrSignalResource<u32, FromToBytesCodec>>().read_only())
+}
+
+#[component]
+fn BytesSetter() -> impl IntoView {
+    let sr = expect_context::<SsrSignalResource<u32, FromToBytesCodec>>();
+    view! {
+        {sr.set_with(|| async move {
+            #[cfg(feature = "ssr")]
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            42
+        })}
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn codec_bytes() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(
+                SsrSignalResource::<u32, FromToBytesCodec>::new_with_codec(0)
+            );
+        }>
+            <BytesIndicator />
+            <BytesSetter />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!>42<!><!>",
+    );
+}
+
+// Deliberately not serializable.
+#[derive(Clone)]
+struct Plain(String);
+
+#[component]
+fn PlainIndicator() -> impl IntoView {
+    expect_context::<SsrOnlySignalResource<Plain>>().render(|plain| view! { <p>{plain.0}</p> })
+}
+
+#[component]
+fn PlainSetter() -> impl IntoView {
+    let sr = expect_context::<SsrOnlySignalResource<Plain>>();
+    fetch_view(move || {
+        let ws = sr.write_only();
+        async move {
+            #[cfg(feature = "ssr")]
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            ws.set(Plain("Hello world!".to_string()));
+        }
+    })
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn server_only_render() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrOnlySignalResource::new(Plain("default".to_string())));
+        }>
+            <PlainIndicator />
+            <PlainSetter />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!><div><p>Hello world!</p></div><!><!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn server_only_no_writer() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrOnlySignalResource::new(Plain("default".to_string())));
+        }>
+            <PlainIndicator />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!><div><p>default</p></div><!>",
+    );
+}
+
+#[component]
+fn StoredIndicator() -> impl IntoView {
+    let res = expect_context::<StoredSsrSignalResource<String>>().read_only();
+    view! {
+        <p>
+            "Stored is: "
+            <Suspense>
+            // the `Resource` is `Copy` so no clones are needed.
+            {move || Suspend::new(async move { res.await })}
+            </Suspense>
+        </p>
+    }
+}
+
+#[component]
+fn StoredSetter() -> impl IntoView {
+    let sr = expect_context::<StoredSsrSignalResource<String>>();
+    view! {
+        {sr.set_with(|| async move {
+            #[cfg(feature = "ssr")]
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            "Hello world!".to_string()
+        })}
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn stored_render() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(StoredSsrSignalResource::new(String::new()));
+        }>
+            <StoredIndicator />
+            <StoredSetter />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!><p>Stored is: <!>Hello world!</p><!><!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn stored_named_with_codec() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            let stored = StoredSsrSignalResource::<u32, FromToBytesCodec>::new_named_with_codec(
+                "answer",
+                0,
+            );
+            provide_context(stored.inner());
+        }>
+            <BytesIndicator />
+            <BytesSetter />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!>42<!><!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[component]
+fn Flash() -> impl IntoView {
+    let messages = expect_context::<SsrCollector<String>>().read_only();
+    view! {
+        <ul>
+            <Suspense>
+            {move || {
+                let messages = messages.clone();
+                Suspend::new(async move {
+                    messages.await
+                        .into_iter()
+                        .map(|message| view! { <li>{message}</li> })
+                        .collect_view()
+                })
+            }}
+            </Suspense>
+        </ul>
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[component]
+fn Contributor(delay: u64, messages: &'static [&'static str]) -> impl IntoView {
+    let collector = expect_context::<SsrCollector<String>>();
+    view! {
+        {collector.contribute_with(move || async move {
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            messages.iter().map(|message| message.to_string()).collect()
+        })}
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[component]
+fn Pusher() -> impl IntoView {
+    let contributor = expect_context::<SsrCollector<String>>().contributor();
+    contributor.push("Pushed".to_string());
+    contributor.push("twice".to_string());
+    contributor.finish();
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn collector_view_tree_order() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrCollector::<String>::new());
+        }>
+            <Flash />
+            <Contributor delay=150 messages=&["First"] />
+            <Pusher />
+            <Contributor delay=10 messages=&["Second", "Third"] />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!><ul><li>First</li><li>Pushed</li><li>twice</li><li>Second</li><li>Third</li><!></ul><!><!><!><!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn collector_no_contributor() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrCollector::<String>::new());
+        }>
+            <Flash />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        timeout(
+            Duration::from_millis(500),
+            app.to_html_stream_in_order().collect::<String>()
+        )
+        .await
+        .expect("no contributors should not lock"),
+        "<!><ul><!></ul><!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn collector_owner_released() {
+    use leptos_sync_ssr::component::SyncScope;
+
+    let _ = any_spawner::Executor::init_tokio();
+    let owner = Owner::new();
+    let weak = owner.downgrade();
+    owner.with(|| {
+        // as typically done by the setup of `SyncSsrSignal`.
+        SyncScope::new()
+            .ready(false)
+            .setup(|| provide_context(SsrCollector::<String>::new()))
+            .enter()
+            .complete();
+    });
+    drop(owner);
+    assert!(weak.upgrade().is_none());
+}
+
+#[cfg(feature = "ssr")]
+#[component]
+fn TxIndicator() -> impl IntoView {
+    let title = expect_context::<SsrSignalResource<String>>().read_only();
+    let count = expect_context::<SsrSignalResource<u32>>();
+    view! {
+        <Suspense>
+        {move || {
+            let title = title.clone();
+            let count = count.clone();
+            Suspend::new(async move {
+                let title = title.await;
+                // the other resource in the transaction must have been
+                // written to by the time this one is released.
+                format!("{title} {}", count.inner_read_only().get_untracked())
+            })
+        }}
+        </Suspense>
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[component]
+fn TxWriter(commit: bool) -> impl IntoView {
+    let title = expect_context::<SsrSignalResource<String>>();
+    let count = expect_context::<SsrSignalResource<u32>>();
+    fetch_view(move || {
+        let tx = SsrTransaction::new();
+        let title = tx.write(&title);
+        let count = tx.write(&count);
+        async move {
+            title.set("Hello world!".to_string());
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            count.set(42);
+            if commit {
+                tx.commit();
+            } else {
+                tx.abort();
+            }
+        }
+    })
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn transaction_commit() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrSignalResource::new(String::new()));
+            provide_context(SsrSignalResource::new(0u32));
+        }>
+            <TxIndicator />
+            <TxWriter commit=true />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        app.to_html_stream_in_order().collect::<String>().await,
+        "<!>Hello world! 42<!><!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn transaction_abort() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal setup=|| {
+            provide_context(SsrSignalResource::new_must_notify(String::from("Unchanged")));
+            provide_context(SsrSignalResource::new(0u32));
+        }>
+            <TxIndicator />
+            <TxWriter commit=false />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        timeout(
+            Duration::from_millis(500),
+            app.to_html_stream_in_order().collect::<String>()
+        )
+        .await
+        .expect("abort should release the readers"),
+        "<!>Unchanged 0<!><!>",
+    );
+}
+
 #[cfg(feature = "ssr")]
 fn init_renderer() -> Owner {
     let _ = any_spawner::Executor::init_tokio();
@@ -494,3 +1064,114 @@ fn init_renderer() -> Owner {
     owner.set();
     owner
 }
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn navigation_swap_batches_staged_writes() {
+    let _owner = init_renderer();
+
+    let swap = NavigationSwap::provide();
+    let pending = swap.pending();
+    let first = ArcRwSignal::new("old nav");
+    let second = ArcRwSignal::new("old info");
+
+    // the previous route clears as it is cleaned up, while the writers of
+    // the new route hold the swap before they resolve.
+    swap.stage({
+        let first = first.clone();
+        move || first.set("")
+    });
+    let hold_first = swap.hold();
+    let hold_second = swap.hold();
+    assert!(pending.get_untracked());
+
+    tokio::task::yield_now().await;
+    assert_eq!(first.get_untracked(), "old nav");
+
+    swap.stage({
+        let first = first.clone();
+        move || first.set("new nav")
+    });
+    drop(hold_first);
+    tokio::task::yield_now().await;
+    assert_eq!(first.get_untracked(), "old nav");
+    assert!(pending.get_untracked());
+
+    swap.stage({
+        let second = second.clone();
+        move || second.set("new info")
+    });
+    tokio::task::yield_now().await;
+    assert_eq!(second.get_untracked(), "old info");
+    drop(hold_second);
+    timeout(Duration::from_millis(500), async {
+        while pending.get_untracked() {
+            tokio::task::yield_now().await;
+        }
+    })
+    .await
+    .expect("the staged writes should be flushed");
+    assert_eq!(first.get_untracked(), "new nav");
+    assert_eq!(second.get_untracked(), "new info");
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn async_setup_gates_readers() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal
+            setup=|| provide_context(SsrSignalResource::new(String::new()))
+            async_setup=|| async {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                expect_context::<SsrSignalResource<String>>()
+                    .inner_write_only()
+                    .set("From setup".to_string());
+            }
+        >
+            // without any writers, the reader would otherwise be released
+            // with the initial value as soon as the children are rendered.
+            <Indicator />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        timeout(
+            Duration::from_millis(1000),
+            app.to_html_stream_in_order().collect::<String>()
+        )
+        .await
+        .expect("the async setup should release the readers"),
+        "<!><p>Indicator is: <!>From setup</p><!>",
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[tokio::test]
+async fn async_setup_closure_after_setup() {
+    let _owner = init_renderer();
+
+    let app = view! {
+        <SyncSsrSignal
+            setup=|| provide_context(SsrSignalResource::new(String::new()))
+            async_setup=|| {
+                // expected in the closure body rather than in the future.
+                let ws = expect_context::<SsrSignalResource<String>>().inner_write_only();
+                async move {
+                    ws.set("From setup".to_string());
+                }
+            }
+        >
+            <Indicator />
+        </SyncSsrSignal>
+    };
+    assert_eq!(
+        timeout(
+            Duration::from_millis(1000),
+            app.to_html_stream_in_order().collect::<String>()
+        )
+        .await
+        .expect("the async setup should release the readers"),
+        "<!><p>Indicator is: <!>From setup</p><!>",
+    );
+}